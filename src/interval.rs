@@ -0,0 +1,338 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+pub trait Bound: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(impl Bound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+impl_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    start: T,
+    last: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn closed(start: T, last: T) -> Option<Self> {
+        (start <= last).then_some(Interval { start, last })
+    }
+
+    pub fn half_open(start: T, end: T) -> Option<Self> {
+        (start < end).then(|| Interval {
+            start,
+            last: end - T::ONE,
+        })
+    }
+
+    pub fn with_len(start: T, len: T) -> Option<Self> {
+        if len <= T::ZERO {
+            return None;
+        }
+        Some(Interval {
+            start,
+            last: start.checked_add(len - T::ONE)?,
+        })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn last(&self) -> T {
+        self.last
+    }
+
+    pub fn end(&self) -> Option<T> {
+        self.last.checked_add(T::ONE)
+    }
+
+    pub fn len(&self) -> Option<T> {
+        self.last.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.last
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.last <= self.last
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::closed(self.start.max(other.start), self.last.min(other.last))
+    }
+
+    fn touches(&self, other: &Interval<T>) -> bool {
+        match self.last.checked_add(T::ONE) {
+            Some(next) => other.start <= next,
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn normalise(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.sort();
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(previous) if previous.touches(&interval) => {
+                    previous.last = previous.last.max(interval.last);
+                }
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalise(intervals);
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |total, interval| {
+            total.checked_add(interval.len()?)
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.last < value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.last < other.start);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains_interval(other))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::normalise(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut res = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            if let Some(overlap) = a.intersection(&b) {
+                res.push(overlap);
+            }
+            if a.last < b.last {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals: res }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut res = vec![];
+        for interval in &self.intervals {
+            let mut remaining = Some(*interval);
+            for cut in other.iter() {
+                let Some(current) = remaining else {
+                    break;
+                };
+                if cut.last < current.start {
+                    continue;
+                }
+                if cut.start > current.last {
+                    break;
+                }
+                if let Some(before) = cut.start.checked_sub(T::ONE) {
+                    if let Some(piece) = Interval::closed(current.start, before) {
+                        res.push(piece);
+                    }
+                }
+                remaining = cut
+                    .last
+                    .checked_add(T::ONE)
+                    .and_then(|after| Interval::closed(after, current.last));
+            }
+            res.extend(remaining);
+        }
+        IntervalSet { intervals: res }
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::normalise(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, IntervalSet};
+
+    fn set(intervals: &[(u32, u32)]) -> IntervalSet<u32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::half_open(start, end).unwrap())
+            .collect()
+    }
+
+    fn bounds(set: &IntervalSet<u32>) -> Vec<(u32, u32)> {
+        set.iter()
+            .map(|interval| (interval.start(), interval.end().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn conventions() {
+        assert_eq!(Interval::half_open(3, 7), Interval::closed(3, 6));
+        assert_eq!(Interval::with_len(3, 4), Interval::closed(3, 6));
+        assert_eq!(Interval::half_open(3, 3), None);
+        assert_eq!(Interval::closed(3, 3).unwrap().len(), Some(1));
+        assert_eq!(Interval::with_len(250u8, 10), None);
+
+        let top = Interval::closed(250u8, 255).unwrap();
+        assert_eq!(top.end(), None);
+        assert_eq!(top.len(), Some(6));
+        assert!(top.contains(255));
+    }
+
+    #[test]
+    fn normalise_merges_overlapping_and_adjacent() {
+        let merged = set(&[(10, 20), (0, 5), (5, 8), (15, 25), (30, 31)]);
+        assert_eq!(bounds(&merged), [(0, 8), (10, 25), (30, 31)]);
+        assert_eq!(merged.len(), Some(24));
+        assert!(merged.contains(7));
+        assert!(!merged.contains(8));
+        assert!(merged.contains_interval(&Interval::closed(12, 20).unwrap()));
+        assert!(!merged.contains_interval(&Interval::closed(7, 12).unwrap()));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(bounds(&a.union(&b)), [(0, 40)]);
+        assert_eq!(bounds(&a.intersection(&b)), [(5, 10), (20, 25), (28, 30)]);
+        assert_eq!(bounds(&a.difference(&b)), [(0, 5), (25, 28)]);
+        assert_eq!(bounds(&b.difference(&a)), [(10, 20), (30, 40)]);
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = IntervalSet::new();
+        c.insert(Interval::closed(1, 2).unwrap());
+        c.insert(Interval::closed(3, 4).unwrap());
+        assert_eq!(bounds(&c), [(1, 5)]);
+    }
+
+    #[test]
+    fn signed_and_boundary_values() {
+        let full: IntervalSet<i8> = [Interval::closed(i8::MIN, i8::MAX).unwrap()]
+            .into_iter()
+            .collect();
+        let hole: IntervalSet<i8> = [Interval::closed(-1, 1).unwrap()].into_iter().collect();
+        let rest = full.difference(&hole);
+        assert_eq!(
+            rest.intervals(),
+            [
+                Interval::closed(i8::MIN, -2).unwrap(),
+                Interval::closed(2, i8::MAX).unwrap()
+            ]
+        );
+        assert_eq!(rest.union(&hole), full);
+
+        let top: IntervalSet<u64> = [
+            Interval::closed(u64::MAX - 1, u64::MAX).unwrap(),
+            Interval::closed(u64::MAX, u64::MAX).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(top.len(), Some(2));
+    }
+
+    #[test]
+    fn full_width_length_overflows() {
+        let full = Interval::closed(0u8, 255).unwrap();
+        assert_eq!(full.len(), None);
+        assert_eq!(Interval::closed(0u8, 254).unwrap().len(), Some(255));
+        assert_eq!(Interval::closed(i8::MIN, i8::MAX).unwrap().len(), None);
+
+        let set: IntervalSet<i8> = [
+            Interval::closed(-128, -2).unwrap(),
+            Interval::closed(0, 1).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), None);
+        let set: IntervalSet<u8> = [Interval::closed(0, 100).unwrap()].into_iter().collect();
+        assert_eq!(set.len(), Some(101));
+    }
+}
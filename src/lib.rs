@@ -1,64 +1,142 @@
-pub mod day_1;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
-
-use std::time::SystemTime;
-use std::{env, fs};
-
-use dotenv;
-use reqwest::blocking::Client;
-use reqwest::Method;
+pub mod interval;
+pub mod registry;
+pub mod repl;
+pub mod snapshot;
+pub mod status;
+#[cfg(feature = "year_2023")]
+pub mod year_2023;
+
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use fancy_regex;
+#[doc(hidden)]
+pub use once_cell;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    input_path_in(Path::new(""), year, day)
+}
+
+fn input_path_in(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("input/{year}/day{day}.txt"))
+}
+
+fn legacy_input_path_in(root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    (year == 2023).then(|| root.join(format!("day{day}.txt")))
+}
 
 pub fn download_day(year: u16, day: u8) -> String {
-    let path = &format!("day{day}.txt")[..];
-    if let Ok(_) = fs::metadata(path) {
+    download_day_in(Path::new(""), year, day)
+}
+
+pub fn download_day_in(root: &Path, year: u16, day: u8) -> String {
+    let path = input_path_in(root, year, day);
+    if fs::metadata(&path).is_ok() {
         return fs::read_to_string(path).unwrap();
     }
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    if let Some(legacy) = legacy_input_path_in(root, year, day).filter(|p| fs::metadata(p).is_ok())
+    {
+        let content = fs::read_to_string(legacy).unwrap();
+        fs::write(path, &content).unwrap();
+        return content;
+    }
+    let response = fetch_day(year, day);
+
+    fs::write(path, &response[..]).unwrap();
+
+    response
+}
+
+#[cfg(feature = "net")]
+fn fetch_day(year: u16, day: u8) -> String {
+    use reqwest::blocking::Client;
+    use reqwest::Method;
+    use std::env;
+
     dotenv::dotenv().unwrap();
     let cookie = env::var("AOC_COOKIE").expect("Expected cookie");
     let url = format!("https://adventofcode.com/{year}/day/{day}/input");
     let client = Client::new();
-    let response = client
+    client
         .request(Method::GET, url)
         .header("Cookie", format!("session={cookie}"))
         .send()
         .unwrap()
         .text()
-        .unwrap();
-
-    fs::write(path, &response[..]).unwrap();
+        .unwrap()
+}
 
-    response
+#[cfg(not(feature = "net"))]
+fn fetch_day(year: u16, day: u8) -> String {
+    panic!("Input for {year} day {day} is not cached and the `net` feature is disabled")
 }
-pub fn timeit<F: Fn() -> T, T>(f: F) -> T {
+
+pub fn time<F: Fn() -> T, T>(f: F) -> (T, Duration) {
     let start = SystemTime::now();
     let result = f();
     let end = SystemTime::now();
-    let duration = end.duration_since(start).unwrap();
+    (result, end.duration_since(start).unwrap())
+}
+
+pub fn timeit<F: Fn() -> T, T>(f: F) -> T {
+    let (result, duration) = time(f);
     println!("it took {} seconds", duration.as_secs_f64());
     result
 }
+
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! regex {
+    ($re:literal) => {{
+        static RE: $crate::once_cell::sync::Lazy<$crate::fancy_regex::Regex> =
+            $crate::once_cell::sync::Lazy::new(|| $crate::fancy_regex::Regex::new($re).unwrap());
+        &*RE
+    }};
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub(crate) struct TestRng(u64);
+
+#[cfg(test)]
+#[allow(dead_code)]
+impl TestRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        TestRng(seed)
+    }
+
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (self.0 >> 33) as usize % bound
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::download_day;
+    use std::fs;
+
+    use std::env;
+
+    use super::{download_day, download_day_in, input_path_in};
 
     #[test]
     fn sample_test() {
         println!("{}", download_day(2023, 1));
     }
+
+    #[test]
+    fn legacy_cache_is_reused() {
+        let root = env::temp_dir().join(format!("aoc-legacy-cache-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("day25.txt"), "cached").unwrap();
+        let content = download_day_in(&root, 2023, 25);
+        let migrated = fs::read_to_string(input_path_in(&root, 2023, 25)).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(content, "cached");
+        assert_eq!(migrated, "cached");
+    }
 }
@@ -1,3 +1,79 @@
+use std::{env, io, panic};
+
+use aoc_2023::{download_day, registry::registry, repl, status, time};
+
 fn main() {
-    println!("Hello, welcome to Anuraj's Advent of Code 2023! \nGo to any of the days and run the tests to see the results for each part :)");
+    let mut year: u16 = 2023;
+    let mut day: Option<u8> = None;
+    let mut command: Vec<String> = vec![];
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--year" => {
+                year = args
+                    .next()
+                    .and_then(|y| y.parse().ok())
+                    .expect("Expected a year after --year")
+            }
+            "--day" => {
+                day = Some(
+                    args.next()
+                        .and_then(|d| d.parse().ok())
+                        .expect("Expected a day after --day"),
+                )
+            }
+            _ => command.push(arg),
+        }
+    }
+
+    let registry = registry();
+    let solutions = registry
+        .get(&year)
+        .unwrap_or_else(|| panic!("No solutions registered for {year}"));
+
+    match &command.iter().map(|arg| &arg[..]).collect::<Vec<_>>()[..] {
+        [] => {
+            println!("Hello, welcome to Anuraj's Advent of Code {year}!");
+            for solution in solutions
+                .iter()
+                .filter(|solution| day.is_none_or(|day| day == solution.day))
+            {
+                let content = download_day(year, solution.day);
+                println!("Day {}", solution.day);
+                for (part, part_fn) in [(1, solution.part_one), (2, solution.part_two)] {
+                    let (res, duration) = time(|| part_fn(&content));
+                    let seconds = duration.as_secs_f64();
+                    println!("Part {part}: {res} ({seconds} seconds)");
+                    status::record_run(year, solution.day, part, seconds, &res);
+                }
+            }
+        }
+        ["repl", day] => {
+            let day: u8 = day.parse().expect("Expected a day after repl");
+            let solution = solutions
+                .iter()
+                .find(|solution| solution.day == day)
+                .unwrap_or_else(|| panic!("No solution registered for {year} day {day}"));
+            let content = download_day(year, day);
+            repl::run(solution, &content, io::stdin().lock(), io::stdout()).unwrap();
+        }
+        ["status"] => {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(|_| {}));
+            let days = status::status(year, solutions);
+            panic::set_hook(hook);
+            print!("{}", status::render(year, &days));
+        }
+        ["accept", day, part] => {
+            let day: u8 = day.parse().expect("Expected a day after accept");
+            let part: u8 = part.parse().expect("Expected a part after the day");
+            let runs = status::read_runs(year);
+            let (_, answer) = runs
+                .get(&(day, part))
+                .unwrap_or_else(|| panic!("Day {day} part {part} has not been run yet"));
+            status::accept_answer(year, day, part, answer);
+            println!("Accepted {answer} for day {day} part {part}");
+        }
+        _ => panic!("Unexpected command {}", command.join(" ")),
+    }
 }
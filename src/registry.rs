@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use crate::repl::Explore;
+
+pub struct Sample {
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+pub type Explorer = fn(&str) -> Box<dyn Explore + '_>;
+
+pub struct Solution {
+    pub day: u8,
+    pub part_one: fn(&str) -> String,
+    pub part_two: fn(&str) -> String,
+    pub samples: &'static [Sample],
+    pub explore: Option<Explorer>,
+}
+
+#[macro_export]
+macro_rules! solution {
+    ($day:literal, $module:ident) => {
+        $crate::solution!(@build $day, $module, None)
+    };
+    ($day:literal, $module:ident, explore) => {
+        $crate::solution!(@build $day, $module, Some($module::explore))
+    };
+    (@build $day:literal, $module:ident, $explore:expr) => {
+        $crate::registry::Solution {
+            day: $day,
+            part_one: |content| $module::part_one(content).to_string(),
+            part_two: |content| $module::part_two(content).to_string(),
+            samples: $module::SAMPLES,
+            explore: $explore,
+        }
+    };
+}
+
+pub fn registry() -> BTreeMap<u16, Vec<Solution>> {
+    BTreeMap::from_iter([
+        #[cfg(feature = "year_2023")]
+        (2023, crate::year_2023::solutions()),
+    ])
+}
@@ -0,0 +1,131 @@
+use std::{
+    io::{self, BufRead, Write},
+    panic,
+};
+
+use crate::registry::{Sample, Solution};
+
+pub trait Explore {
+    fn show(&self) -> String;
+    fn query(&self, command: &str, args: &[&str]) -> Option<String>;
+    fn commands(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Source {
+    Input,
+    Sample(usize),
+}
+
+pub fn run<R: BufRead, W: Write>(
+    solution: &Solution,
+    input: &str,
+    reader: R,
+    mut writer: W,
+) -> io::Result<()> {
+    let mut lines = reader.lines();
+    let mut source = Source::Input;
+    loop {
+        let (content, sample): (&str, Option<&Sample>) = match source {
+            Source::Input => (input, None),
+            Source::Sample(idx) => (solution.samples[idx].input, Some(&solution.samples[idx])),
+        };
+        let explorer = solution.explore.map(|explore| explore(content));
+        match source {
+            Source::Input => writeln!(writer, "Loaded input for day {}", solution.day)?,
+            Source::Sample(idx) => {
+                writeln!(writer, "Loaded sample {} for day {}", idx + 1, solution.day)?
+            }
+        }
+
+        source = loop {
+            write!(writer, "day {}> ", solution.day)?;
+            writer.flush()?;
+            let Some(line) = lines.next() else {
+                return Ok(());
+            };
+            let line = line?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                [] => {}
+                ["quit"] | ["exit"] => return Ok(()),
+                ["help"] => {
+                    writeln!(writer, "show, part <1|2>, sample <n>, input, quit")?;
+                    if let Some(explorer) = &explorer {
+                        writeln!(writer, "{}", explorer.commands().join(", "))?;
+                    }
+                }
+                ["show"] => match &explorer {
+                    Some(explorer) => writeln!(writer, "{}", explorer.show())?,
+                    None => writeln!(writer, "{content}")?,
+                },
+                ["part", part] => {
+                    let (part_fn, expected) = match part {
+                        "1" => (solution.part_one, sample.and_then(|s| s.part_one)),
+                        "2" => (solution.part_two, sample.and_then(|s| s.part_two)),
+                        _ => {
+                            writeln!(writer, "Expected part 1 or 2 found {part}")?;
+                            continue;
+                        }
+                    };
+                    match (panic::catch_unwind(|| part_fn(content)), expected) {
+                        (Ok(res), Some(expected)) => {
+                            writeln!(writer, "{res} (expected {expected})")?
+                        }
+                        (Ok(res), None) => writeln!(writer, "{res}")?,
+                        (Err(_), _) => writeln!(writer, "Part {part} panicked")?,
+                    }
+                }
+                ["input"] => break Source::Input,
+                ["sample", n] => match n.parse::<usize>() {
+                    Ok(n) if n >= 1 && n <= solution.samples.len() => break Source::Sample(n - 1),
+                    _ => writeln!(
+                        writer,
+                        "Expected a sample between 1 and {}",
+                        solution.samples.len()
+                    )?,
+                },
+                [command, ref args @ ..] => {
+                    match explorer
+                        .as_ref()
+                        .and_then(|explorer| explorer.query(command, args))
+                    {
+                        Some(output) => writeln!(writer, "{output}")?,
+                        None => writeln!(writer, "Unknown command {command}, try help")?,
+                    }
+                }
+            }
+        };
+    }
+}
+
+#[cfg(all(test, feature = "year_2023_day_8"))]
+mod tests {
+    use std::io::Cursor;
+
+    use super::run;
+    use crate::registry::registry;
+
+    #[test]
+    fn sample_session() {
+        let registry = registry();
+        let solution = registry[&2023].iter().find(|s| s.day == 8).unwrap();
+        let commands = "node AAA\nwalk AAA\npart 1\nsample 2\npart 2\nbogus\nquit\n";
+        let mut output = vec![];
+        run(
+            solution,
+            crate::year_2023::day_8::SAMPLE,
+            Cursor::new(commands),
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Mapping { start: \"AAA\", left: \"BBB\", right: \"CCC\" }"));
+        assert!(output.contains("AAA reaches a Z node in 2 steps"));
+        assert!(output.contains("Loaded sample 2 for day 8"));
+        assert!(output.contains("6 (expected 6)"));
+        assert!(output.contains("Unknown command bogus, try help"));
+    }
+}
@@ -0,0 +1,37 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+};
+
+pub trait Snapshot {
+    fn snapshot(&self) -> String;
+}
+
+impl<T: Debug> Snapshot for BTreeSet<T> {
+    fn snapshot(&self) -> String {
+        self.iter().map(|value| format!("{value:?}\n")).collect()
+    }
+}
+
+impl<K: Debug, V: Debug> Snapshot for BTreeMap<K, V> {
+    fn snapshot(&self) -> String {
+        self.iter()
+            .map(|(key, value)| format!("{key:?}: {value:?}\n"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::Snapshot;
+
+    #[test]
+    fn snapshot_is_ordered() {
+        let set = BTreeSet::from([(3, 1), (1, 2), (2, 0)]);
+        assert_eq!(set.snapshot(), "(1, 2)\n(2, 0)\n(3, 1)\n");
+        let map = BTreeMap::from([(7, vec!["b"]), (0, vec!["a", "c"])]);
+        assert_eq!(map.snapshot(), "0: [\"a\", \"c\"]\n7: [\"b\"]\n");
+    }
+}
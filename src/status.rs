@@ -0,0 +1,220 @@
+use std::{collections::BTreeMap, fs, panic, path::PathBuf};
+
+use crate::{input_path, registry::Solution};
+
+pub struct PartStatus {
+    pub known: Option<String>,
+    pub accepted: Option<String>,
+    pub seconds: Option<f64>,
+}
+
+pub struct DayStatus {
+    pub day: u8,
+    pub registered: bool,
+    pub cached: bool,
+    pub samples: Option<(usize, usize)>,
+    pub parts: [PartStatus; 2],
+}
+
+pub fn runs_path(year: u16) -> PathBuf {
+    PathBuf::from(format!("input/{year}/runs.txt"))
+}
+
+pub fn answers_path(year: u16) -> PathBuf {
+    PathBuf::from(format!("input/{year}/answers.txt"))
+}
+
+pub fn read_runs(year: u16) -> BTreeMap<(u8, u8), (f64, String)> {
+    let content = fs::read_to_string(runs_path(year)).unwrap_or_default();
+    let mut runs = BTreeMap::new();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        if let (Some(day), Some(part), Some(seconds), Some(answer)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        {
+            if let (Ok(day), Ok(part), Ok(seconds)) = (day.parse(), part.parse(), seconds.parse()) {
+                runs.insert((day, part), (seconds, answer.to_string()));
+            }
+        }
+    }
+    runs
+}
+
+pub fn record_run(year: u16, day: u8, part: u8, seconds: f64, answer: &str) {
+    let mut runs = read_runs(year);
+    runs.insert((day, part), (seconds, answer.to_string()));
+    let content: String = runs
+        .iter()
+        .map(|((day, part), (seconds, answer))| format!("{day} {part} {seconds} {answer}\n"))
+        .collect();
+    let path = runs_path(year);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+pub fn read_answers(year: u16) -> BTreeMap<(u8, u8), String> {
+    let content = fs::read_to_string(answers_path(year)).unwrap_or_default();
+    let mut answers = BTreeMap::new();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        if let (Some(day), Some(part), Some(answer)) = (parts.next(), parts.next(), parts.next()) {
+            if let (Ok(day), Ok(part)) = (day.parse(), part.parse()) {
+                answers.insert((day, part), answer.to_string());
+            }
+        }
+    }
+    answers
+}
+
+pub fn accept_answer(year: u16, day: u8, part: u8, answer: &str) {
+    let mut answers = read_answers(year);
+    answers.insert((day, part), answer.to_string());
+    let content: String = answers
+        .iter()
+        .map(|((day, part), answer)| format!("{day} {part} {answer}\n"))
+        .collect();
+    let path = answers_path(year);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+pub fn check_samples(solution: &Solution) -> (usize, usize) {
+    let mut passed = 0;
+    let mut total = 0;
+    for sample in solution.samples {
+        for (part_fn, expected) in [
+            (solution.part_one, sample.part_one),
+            (solution.part_two, sample.part_two),
+        ] {
+            if let Some(expected) = expected {
+                total += 1;
+                if panic::catch_unwind(|| part_fn(sample.input)).is_ok_and(|res| res == expected) {
+                    passed += 1;
+                }
+            }
+        }
+    }
+    (passed, total)
+}
+
+pub fn status(year: u16, solutions: &[Solution]) -> Vec<DayStatus> {
+    let runs = read_runs(year);
+    let answers = read_answers(year);
+    (1..=25)
+        .map(|day| {
+            let solution = solutions.iter().find(|solution| solution.day == day);
+            let part = |part: u8| PartStatus {
+                known: runs.get(&(day, part)).map(|(_, answer)| answer.clone()),
+                accepted: answers.get(&(day, part)).cloned(),
+                seconds: runs.get(&(day, part)).map(|(seconds, _)| *seconds),
+            };
+            DayStatus {
+                day,
+                registered: solution.is_some(),
+                cached: input_path(year, day).exists(),
+                samples: solution.map(check_samples),
+                parts: [part(1), part(2)],
+            }
+        })
+        .collect()
+}
+
+fn part_mark(part: &PartStatus) -> char {
+    match (&part.known, &part.accepted) {
+        (Some(known), Some(accepted)) if known == accepted => '*',
+        (Some(_), Some(_)) => '!',
+        (None, Some(_)) => '*',
+        (Some(_), None) => '?',
+        (None, None) => '.',
+    }
+}
+
+pub fn render(year: u16, days: &[DayStatus]) -> String {
+    let mut res = format!("Advent of Code {year}\n\n");
+    for week in days.chunks(5) {
+        for day in week {
+            if day.registered {
+                res.push_str(&format!(
+                    " {:>2} {}{} ",
+                    day.day,
+                    part_mark(&day.parts[0]),
+                    part_mark(&day.parts[1])
+                ));
+            } else {
+                res.push_str(&format!(" {:>2} -- ", day.day));
+            }
+        }
+        res.push('\n');
+    }
+    res.push_str("\n* accepted  ? solved without an accepted answer  ! differs from accepted  . not run  -- missing\n\n");
+
+    for day in days.iter().filter(|day| day.registered || day.cached) {
+        res.push_str(&format!(
+            "Day {:>2}  module: {:<3}  input: {:<6}  samples: {:<5}",
+            day.day,
+            if day.registered { "yes" } else { "no" },
+            if day.cached { "cached" } else { "none" },
+            match day.samples {
+                Some((passed, total)) => format!("{passed}/{total}"),
+                None => "-".to_string(),
+            }
+        ));
+        for (idx, part) in day.parts.iter().enumerate() {
+            res.push_str(&format!(
+                "  part {}: {} / {} ({})",
+                idx + 1,
+                part.known.as_deref().unwrap_or("-"),
+                part.accepted.as_deref().unwrap_or("-"),
+                match part.seconds {
+                    Some(seconds) => format!("{seconds:.3}s"),
+                    None => "-".to_string(),
+                }
+            ));
+        }
+        res.push('\n');
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_samples, render, DayStatus, PartStatus};
+    use crate::registry::registry;
+
+    #[test]
+    fn samples_pass() {
+        for solution in registry().get(&2023).into_iter().flatten() {
+            let (passed, total) = check_samples(solution);
+            assert_eq!(passed, total, "day {} samples", solution.day);
+        }
+    }
+
+    #[test]
+    fn render_grid() {
+        let days: Vec<DayStatus> = (1..=25)
+            .map(|day| DayStatus {
+                day,
+                registered: day <= 2,
+                cached: day == 1,
+                samples: (day <= 2).then_some((2, 2)),
+                parts: [
+                    PartStatus {
+                        known: (day == 1).then(|| "142".to_string()),
+                        accepted: (day == 1).then(|| "142".to_string()),
+                        seconds: (day == 1).then_some(0.5),
+                    },
+                    PartStatus {
+                        known: (day == 1).then(|| "281".to_string()),
+                        accepted: None,
+                        seconds: None,
+                    },
+                ],
+            })
+            .collect();
+        let res = render(2023, &days);
+        assert!(res.contains("  1 *?   2 ..   3 --   4 --   5 -- \n"));
+        assert!(res.contains(" 21 --  22 --  23 --  24 --  25 -- \n"));
+        assert!(res.contains("part 1: 142 / 142 (0.500s)  part 2: 281 / - (-)"));
+        assert!(!res.contains("Day  3"));
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "year_2023_day_1")]
+pub mod day_1;
+#[cfg(feature = "year_2023_day_10")]
+pub mod day_10;
+#[cfg(feature = "year_2023_day_11")]
+pub mod day_11;
+#[cfg(feature = "year_2023_day_12")]
+pub mod day_12;
+#[cfg(feature = "year_2023_day_13")]
+pub mod day_13;
+#[cfg(feature = "year_2023_day_14")]
+pub mod day_14;
+#[cfg(feature = "year_2023_day_15")]
+pub mod day_15;
+#[cfg(feature = "year_2023_day_16")]
+pub mod day_16;
+#[cfg(feature = "year_2023_day_17")]
+pub mod day_17;
+#[cfg(feature = "year_2023_day_18")]
+pub mod day_18;
+#[cfg(feature = "year_2023_day_2")]
+pub mod day_2;
+#[cfg(feature = "year_2023_day_3")]
+pub mod day_3;
+#[cfg(feature = "year_2023_day_4")]
+pub mod day_4;
+#[cfg(feature = "year_2023_day_5")]
+pub mod day_5;
+#[cfg(feature = "year_2023_day_6")]
+pub mod day_6;
+#[cfg(feature = "year_2023_day_7")]
+pub mod day_7;
+#[cfg(feature = "year_2023_day_8")]
+pub mod day_8;
+#[cfg(feature = "year_2023_day_9")]
+pub mod day_9;
+
+use crate::registry::Solution;
+
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        #[cfg(feature = "year_2023_day_1")]
+        crate::solution!(1, day_1),
+        #[cfg(feature = "year_2023_day_2")]
+        crate::solution!(2, day_2, explore),
+        #[cfg(feature = "year_2023_day_3")]
+        crate::solution!(3, day_3),
+        #[cfg(feature = "year_2023_day_4")]
+        crate::solution!(4, day_4),
+        #[cfg(feature = "year_2023_day_5")]
+        crate::solution!(5, day_5, explore),
+        #[cfg(feature = "year_2023_day_6")]
+        crate::solution!(6, day_6),
+        #[cfg(feature = "year_2023_day_7")]
+        crate::solution!(7, day_7),
+        #[cfg(feature = "year_2023_day_8")]
+        crate::solution!(8, day_8, explore),
+        #[cfg(feature = "year_2023_day_9")]
+        crate::solution!(9, day_9),
+        #[cfg(feature = "year_2023_day_10")]
+        crate::solution!(10, day_10),
+        #[cfg(feature = "year_2023_day_11")]
+        crate::solution!(11, day_11),
+        #[cfg(feature = "year_2023_day_12")]
+        crate::solution!(12, day_12),
+        #[cfg(feature = "year_2023_day_13")]
+        crate::solution!(13, day_13),
+        #[cfg(feature = "year_2023_day_14")]
+        crate::solution!(14, day_14),
+        #[cfg(feature = "year_2023_day_15")]
+        crate::solution!(15, day_15),
+        #[cfg(feature = "year_2023_day_16")]
+        crate::solution!(16, day_16, explore),
+        #[cfg(feature = "year_2023_day_17")]
+        crate::solution!(17, day_17),
+        #[cfg(feature = "year_2023_day_18")]
+        crate::solution!(18, day_18),
+    ]
+}
@@ -0,0 +1,613 @@
+use fancy_regex::Regex;
+use once_cell::sync::Lazy;
+use std::{
+    cmp::Reverse,
+    collections::VecDeque,
+    error::Error,
+    fmt::{Debug, Display},
+    fs, io,
+    path::Path,
+};
+
+use crate::registry::Sample;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub start: usize,
+    pub text: &'a str,
+    pub value: usize,
+}
+
+impl Token<'_> {
+    pub fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigits {
+    pub line: String,
+}
+
+impl Display for NoDigits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no digits found in line {:?}", self.line)
+    }
+}
+
+impl Error for NoDigits {}
+
+impl NoDigits {
+    fn new(line: &str) -> Self {
+        NoDigits {
+            line: line.to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Scanner {
+    transitions: Vec<[usize; 256]>,
+    outputs: Vec<Vec<(usize, usize)>>,
+}
+
+impl Scanner {
+    pub fn new(entries: &[(String, usize)]) -> Self {
+        let mut transitions = vec![[0; 256]];
+        let mut outputs: Vec<Vec<(usize, usize)>> = vec![vec![]];
+        for (word, value) in entries.iter().filter(|(word, _)| !word.is_empty()) {
+            let mut node = 0;
+            for &byte in word.as_bytes() {
+                if transitions[node][byte as usize] == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(vec![]);
+                    transitions[node][byte as usize] = transitions.len() - 1;
+                }
+                node = transitions[node][byte as usize];
+            }
+            outputs[node].push((word.len(), *value));
+        }
+
+        let mut fail = vec![0; transitions.len()];
+        let mut queue: VecDeque<usize> =
+            transitions[0].iter().copied().filter(|&n| n != 0).collect();
+        while let Some(node) = queue.pop_front() {
+            let inherited = outputs[fail[node]].clone();
+            outputs[node].extend(inherited);
+            let fallback = transitions[fail[node]];
+            for (byte, next) in transitions[node].iter_mut().enumerate() {
+                if *next != 0 {
+                    fail[*next] = fallback[byte];
+                    queue.push_back(*next);
+                } else {
+                    *next = fallback[byte];
+                }
+            }
+        }
+
+        Scanner {
+            transitions,
+            outputs,
+        }
+    }
+
+    pub fn scan<'a>(&self, line: &'a str) -> Vec<Token<'a>> {
+        let mut tokens = vec![];
+        let mut node = 0;
+        for (idx, &byte) in line.as_bytes().iter().enumerate() {
+            node = self.transitions[node][byte as usize];
+            for &(len, value) in &self.outputs[node] {
+                tokens.push(Token {
+                    start: idx + 1 - len,
+                    text: &line[idx + 1 - len..idx + 1],
+                    value,
+                });
+            }
+        }
+        tokens.sort_by_key(|token| (token.start, Reverse(token.end())));
+        tokens
+    }
+}
+
+impl Debug for Scanner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scanner")
+            .field("states", &self.transitions.len())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    entries: Vec<(String, usize)>,
+    regex: Regex,
+    scanner: Scanner,
+}
+
+impl Vocabulary {
+    pub fn new<S: Into<String>>(entries: impl IntoIterator<Item = (S, usize)>) -> Self {
+        let mut entries: Vec<(String, usize)> = entries
+            .into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .collect();
+        entries.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        let alternation = entries
+            .iter()
+            .map(|(word, _)| fancy_regex::escape(word).into_owned())
+            .collect::<Vec<_>>()
+            .join("|");
+        let regex = Regex::new(&format!("(?m)(?=({alternation}))")).unwrap();
+        let scanner = Scanner::new(&entries);
+        Vocabulary {
+            entries,
+            regex,
+            scanner,
+        }
+    }
+
+    pub fn from_words<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self::new(
+            words
+                .into_iter()
+                .enumerate()
+                .map(|(value, word)| (word.into(), value))
+                .chain((0..10).map(|digit| (digit.to_string(), digit))),
+        )
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let mut entries = vec![];
+        for (idx, line) in content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+        {
+            let mut parts = line.split_whitespace();
+            let word = parts.next().unwrap();
+            let value = match parts.next() {
+                Some(value) => value.parse().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("expected a number for {word} found {value}"),
+                    )
+                })?,
+                None => idx,
+            };
+            entries.push((word.to_string(), value));
+        }
+        entries.extend((0..10).map(|digit| (digit.to_string(), digit)));
+        Ok(Self::new(entries))
+    }
+
+    pub fn english() -> Self {
+        Self::new([
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+            ("0", 0),
+            ("1", 1),
+            ("2", 2),
+            ("3", 3),
+            ("4", 4),
+            ("5", 5),
+            ("6", 6),
+            ("7", 7),
+            ("8", 8),
+            ("9", 9),
+        ])
+    }
+
+    pub fn get(&self, word: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == word)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn entries(&self) -> &[(String, usize)] {
+        &self.entries
+    }
+
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    pub fn scan<'a>(&self, line: &'a str) -> Vec<Token<'a>> {
+        self.scanner.scan(line)
+    }
+
+    pub fn tokens<'a>(&self, line: &'a str) -> impl DoubleEndedIterator<Item = Token<'a>> {
+        self.scan(line).into_iter()
+    }
+}
+
+pub static ENGLISH: Lazy<Vocabulary> = Lazy::new(Vocabulary::english);
+
+pub fn calculate_line_one(line: &str) -> Result<usize, NoDigits> {
+    let mut first = None;
+    let mut second = None;
+    for c in line.chars() {
+        if c.is_numeric() {
+            match (first, second) {
+                (None, _) => {
+                    first = Some(c);
+                    second = Some(c);
+                }
+                (_, _) => {
+                    second = Some(c);
+                }
+            }
+        }
+    }
+    let (Some(first), Some(second)) = (first, second) else {
+        return Err(NoDigits::new(line));
+    };
+    let combined = format!("{first}{second}");
+
+    Ok(combined
+        .parse()
+        .expect("expected both digits to be numbers"))
+}
+
+pub fn calculate_line_two(line: &str, vocabulary: &Vocabulary) -> Result<usize, NoDigits> {
+    let mut min = i32::MAX;
+    let mut max = i32::MIN;
+    let mut first = None;
+    let mut second = None;
+    for (digit, val) in vocabulary.entries() {
+        if let Some(idx) = line.find(digit) {
+            if (idx as i32) < min {
+                first = Some(*val);
+                min = idx as i32;
+            }
+        } else {
+            continue;
+        }
+
+        if let Some(idx) = line.rfind(digit) {
+            if (idx as i32) > max {
+                second = Some(*val);
+                max = idx as i32;
+            }
+        }
+    }
+    let (Some(first), Some(second)) = (first, second) else {
+        return Err(NoDigits::new(line));
+    };
+    let combined = format!("{first}{second}");
+
+    Ok(combined
+        .parse()
+        .expect("expected both digits to be numbers"))
+}
+
+pub fn calculate_line_two_regex(line: &str, vocabulary: &Vocabulary) -> Result<usize, NoDigits> {
+    let matches: Vec<_> = vocabulary.regex().captures_iter(line).collect();
+    let (Some(first), Some(second)) = (matches.first(), matches.last()) else {
+        return Err(NoDigits::new(line));
+    };
+    let first = &first.as_ref().unwrap()[1];
+    let second = &second.as_ref().unwrap()[1];
+
+    let combined = format!(
+        "{}{}",
+        vocabulary
+            .get(first)
+            .expect("digit should be in vocabulary"),
+        vocabulary
+            .get(second)
+            .expect("digit should be in vocabulary")
+    );
+
+    Ok(combined
+        .parse()
+        .expect("expected both digits to be numbers"))
+}
+
+pub fn calculate_line_two_scan(line: &str, vocabulary: &Vocabulary) -> Result<usize, NoDigits> {
+    let tokens = vocabulary.scan(line);
+    let (Some(first), Some(last)) = (tokens.first(), tokens.last()) else {
+        return Err(NoDigits::new(line));
+    };
+    let second = tokens
+        .iter()
+        .find(|token| token.start == last.start)
+        .expect("expected a second digit");
+    let combined = format!("{}{}", first.value, second.value);
+
+    Ok(combined
+        .parse()
+        .expect("expected both digits to be numbers"))
+}
+
+pub fn part_one(content: &str) -> usize {
+    content
+        .lines()
+        .map(calculate_line_one)
+        .sum::<Result<usize, _>>()
+        .unwrap()
+}
+
+pub fn part_two(content: &str) -> usize {
+    content
+        .lines()
+        .map(|line| calculate_line_two_scan(line, &ENGLISH))
+        .sum::<Result<usize, _>>()
+        .unwrap()
+}
+
+pub const SAMPLE: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+pub const SAMPLE_2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+pub const SAMPLES: &[Sample] = &[
+    Sample {
+        input: SAMPLE,
+        part_one: Some("142"),
+        part_two: None,
+    },
+    Sample {
+        input: SAMPLE_2,
+        part_one: None,
+        part_two: Some("281"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{
+        calculate_line_one, calculate_line_two, calculate_line_two_regex, calculate_line_two_scan,
+        NoDigits, Token, Vocabulary, ENGLISH,
+    };
+    use crate::{download_day, timeit, TestRng};
+
+    #[test]
+    fn part_1() {
+        let content = download_day(2023, 1);
+        let total = super::part_one(&content);
+        println!("Part 1: {total}");
+    }
+
+    #[test]
+    fn part_2() {
+        let content = download_day(2023, 1);
+        let total = super::part_two(&content);
+
+        println!("Part two: {total}");
+    }
+
+    #[test]
+    fn part_2_regex() {
+        let content = download_day(2023, 1);
+        let total: usize = content
+            .lines()
+            .map(|line| calculate_line_two_regex(line, &ENGLISH))
+            .sum::<Result<usize, _>>()
+            .unwrap();
+
+        println!("Part two: {total}");
+    }
+
+    #[test]
+    fn calculate_line_two_test() {
+        let line = "two1nine";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(29));
+
+        let line = "eightwothree";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(83));
+
+        let line = "abcone2threexyz";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(13));
+
+        let line = "xtwone3four";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(24));
+
+        let line = "4nineeightseven2";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(42));
+
+        let line = "zoneight234";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(14));
+
+        let line = "7pqrstsixteen";
+        assert_eq!(calculate_line_two(line, &ENGLISH), Ok(76));
+    }
+
+    #[test]
+    fn calculate_line_one_test() {
+        let mut line = "1abc2";
+        assert_eq!(calculate_line_one(line), Ok(12));
+
+        line = "pqr3stu8vwx";
+        assert_eq!(calculate_line_one(line), Ok(38));
+
+        line = "a1b2c3d4e5f";
+        assert_eq!(calculate_line_one(line), Ok(15));
+
+        line = "treb7uchet";
+        assert_eq!(calculate_line_one(line), Ok(77));
+    }
+
+    #[test]
+    fn vocabulary_from_words() {
+        let vocabulary = Vocabulary::from_words([
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            "zehn",
+        ]);
+        let line = "xzweinsiebenull";
+        assert_eq!(calculate_line_two(line, &vocabulary), Ok(20));
+        assert_eq!(calculate_line_two_regex(line, &vocabulary), Ok(20));
+
+        let line = "achtzehn";
+        assert_eq!(calculate_line_two(line, &vocabulary), Ok(810));
+        assert_eq!(calculate_line_two_regex(line, &vocabulary), Ok(810));
+    }
+
+    #[test]
+    fn vocabulary_prefers_longest_word() {
+        let vocabulary = Vocabulary::new([("one", 1), ("oneteen", 11), ("two", 2)]);
+        assert_eq!(calculate_line_two("oneteentwo", &vocabulary), Ok(112));
+        assert_eq!(calculate_line_two_regex("oneteentwo", &vocabulary), Ok(112));
+        assert_eq!(calculate_line_two_scan("oneteentwo", &vocabulary), Ok(112));
+    }
+
+    #[test]
+    fn scan_overlapping_tokens() {
+        let tokens = ENGLISH.scan("xtwone3eightwo");
+        let expected = [
+            (1, "two", 2),
+            (3, "one", 1),
+            (6, "3", 3),
+            (7, "eight", 8),
+            (11, "two", 2),
+        ]
+        .map(|(start, text, value)| Token { start, text, value });
+        assert_eq!(tokens, expected);
+        assert_eq!(tokens[3].end(), 12);
+
+        let vocabulary = Vocabulary::new([("one", 1), ("oneteen", 11), ("two", 2)]);
+        let tokens: Vec<_> = vocabulary
+            .tokens("oneteen")
+            .map(|token| (token.start, token.text, token.value))
+            .collect();
+        assert_eq!(tokens, [(0, "oneteen", 11), (0, "one", 1)]);
+    }
+
+    #[test]
+    fn custom_rules_from_tokens() {
+        let line = "a1twothree4fivesix";
+        let sum: usize = ENGLISH.tokens(line).map(|token| token.value).sum();
+        assert_eq!(sum, 21);
+
+        let first_three: Vec<_> = ENGLISH
+            .tokens(line)
+            .take(3)
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(first_three, ["1", "two", "three"]);
+
+        let last_two: Vec<_> = ENGLISH
+            .tokens(line)
+            .rev()
+            .take(2)
+            .map(|token| token.value)
+            .collect();
+        assert_eq!(last_two, [6, 5]);
+    }
+
+    #[test]
+    fn no_digits_is_an_error() {
+        let err = NoDigits {
+            line: "abc".to_string(),
+        };
+        assert_eq!(calculate_line_one("abc"), Err(err.clone()));
+        assert_eq!(calculate_line_two("abc", &ENGLISH), Err(err.clone()));
+        assert_eq!(calculate_line_two_regex("abc", &ENGLISH), Err(err.clone()));
+        assert_eq!(calculate_line_two_scan("abc", &ENGLISH), Err(err.clone()));
+        assert_eq!(err.to_string(), "no digits found in line \"abc\"");
+    }
+
+    #[test]
+    fn zero_is_a_digit() {
+        assert_eq!(calculate_line_one("a0b"), Ok(0));
+        assert_eq!(calculate_line_two("a0b", &ENGLISH), Ok(0));
+        assert_eq!(calculate_line_two_regex("a0b", &ENGLISH), Ok(0));
+        assert_eq!(calculate_line_two_scan("a0b", &ENGLISH), Ok(0));
+        assert_eq!(calculate_line_two_scan("0two", &ENGLISH), Ok(2));
+    }
+
+    #[test]
+    fn calculate_line_two_scan_test() {
+        for (line, expected) in [
+            ("two1nine", 29),
+            ("eightwothree", 83),
+            ("abcone2threexyz", 13),
+            ("xtwone3four", 24),
+            ("4nineeightseven2", 42),
+            ("zoneight234", 14),
+            ("7pqrstsixteen", 76),
+            ("oneight", 18),
+            ("twone", 21),
+        ] {
+            assert_eq!(
+                calculate_line_two_scan(line, &ENGLISH),
+                Ok(expected),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn scan_benchmark() {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "x", "q", "1",
+            "7",
+        ];
+        let mut rng = TestRng::new(42);
+        let content: Vec<String> = (0..20_000)
+            .map(|_| {
+                let mut line = String::from("7");
+                for _ in 0..12 {
+                    line.push_str(words[rng.below(words.len())]);
+                }
+                line
+            })
+            .collect();
+
+        let find: usize = timeit(|| {
+            content
+                .iter()
+                .map(|line| calculate_line_two(line, &ENGLISH))
+                .sum::<Result<usize, _>>()
+                .unwrap()
+        });
+        let regex: usize = timeit(|| {
+            content
+                .iter()
+                .map(|line| calculate_line_two_regex(line, &ENGLISH))
+                .sum::<Result<usize, _>>()
+                .unwrap()
+        });
+        let scan: usize = timeit(|| {
+            content
+                .iter()
+                .map(|line| calculate_line_two_scan(line, &ENGLISH))
+                .sum::<Result<usize, _>>()
+                .unwrap()
+        });
+        assert_eq!(find, regex);
+        assert_eq!(find, scan);
+    }
+
+    #[test]
+    fn vocabulary_from_file() {
+        let path = env::temp_dir().join("aoc_day_1_vocabulary.txt");
+        fs::write(&path, "zero\none\ntwo\n\nten 10\n").unwrap();
+        let vocabulary = Vocabulary::from_file(&path).unwrap();
+        assert_eq!(vocabulary.get("zero"), Some(0));
+        assert_eq!(vocabulary.get("ten"), Some(10));
+        assert_eq!(vocabulary.get("7"), Some(7));
+        assert_eq!(calculate_line_two("tenxzero2one", &vocabulary), Ok(101));
+
+        fs::write(&path, "one uno\n").unwrap();
+        assert!(Vocabulary::from_file(&path).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
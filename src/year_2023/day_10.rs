@@ -1,8 +1,10 @@
 use std::{
     cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::{BTreeSet, BinaryHeap, HashMap, HashSet},
 };
 
+use crate::registry::Sample;
+
 type Point = (usize, usize);
 
 pub fn check_north(current: Point, map: &Vec<Vec<char>>) -> Option<Point> {
@@ -77,7 +79,7 @@ pub fn check_south(current: Point, map: &Vec<Vec<char>>) -> Option<Point> {
     return Some((current.0 + 1, current.1));
 }
 
-pub fn djikstras_furthest(starting: Point, map: Vec<Vec<char>>) -> (usize, HashSet<Point>) {
+pub fn djikstras_furthest(starting: Point, map: Vec<Vec<char>>) -> (usize, BTreeSet<Point>) {
     let mut distance_tracker: HashMap<Point, usize> = HashMap::new();
     let mut visited: HashSet<Point> = HashSet::new();
     distance_tracker.entry(starting).or_insert(0);
@@ -122,10 +124,10 @@ pub fn djikstras_furthest(starting: Point, map: Vec<Vec<char>>) -> (usize, HashS
         x = d;
     }
 
-    (x, visited)
+    (x, visited.into_iter().collect())
 }
 
-pub fn inside_count_line(line: &Vec<char>, line_num: usize, points: &HashSet<Point>) -> usize {
+pub fn inside_count_line(line: &Vec<char>, line_num: usize, points: &BTreeSet<Point>) -> usize {
     let mut count = 0;
     let mut inside = false;
 
@@ -161,50 +163,71 @@ pub fn parse_map(content: &str) -> (Vec<Vec<char>>, Point) {
     (res, start)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::download_day;
+pub fn part_one(content: &str) -> usize {
+    let (map, start) = parse_map(content);
+    let (res, _) = djikstras_furthest(start, map);
+    res
+}
 
-    use super::{djikstras_furthest, inside_count_line, parse_map};
+pub fn part_two(content: &str) -> usize {
+    let (map, start) = parse_map(content);
+    let (_, points) = djikstras_furthest(start, map.clone());
+    map.iter()
+        .enumerate()
+        .map(|(idx, line)| inside_count_line(line, idx, &points))
+        .sum()
+}
 
-    const SAMPLE: &str = "..F7.
+pub const SAMPLE: &str = "..F7.
 .FJ|.
 SJ.L7
 |F--J
 LJ...";
 
+pub const SAMPLE_2: &str = "OF----7F7F7F7F-7OOOO
+O|F--7||||||||FJOOOO
+O||OFJ||||||||L7OOOO
+FJL7L7LJLJ||LJIL-7OO
+L--JOL7IIILJS7F-7L7O
+OOOOF-JIIF7FJ|L7L7L7
+OOOOL7IF7||L7|IL7L7|
+OOOOO|FJLJ|FJ|F7|OLJ
+OOOOFJL-7O||O||||OOO
+OOOOL---JOLJOLJLJOOO";
+
+pub const SAMPLES: &[Sample] = &[
+    Sample {
+        input: SAMPLE,
+        part_one: Some("8"),
+        part_two: None,
+    },
+    Sample {
+        input: SAMPLE_2,
+        part_one: None,
+        part_two: Some("8"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::{download_day, snapshot::Snapshot};
+
+    use super::{djikstras_furthest, inside_count_line, parse_map, SAMPLE, SAMPLE_2};
+
     #[test]
     fn part_one() {
         let content = download_day(2023, 10);
-        let (map, start) = parse_map(&content[..]);
-        let (res, _) = djikstras_furthest(start, map.clone());
+        let res = super::part_one(&content);
         println!("Part One: {res}");
     }
 
     #[test]
     fn part_two() {
         let content = download_day(2023, 10);
-        let (map, start) = parse_map(&content[..]);
-        let (_, points) = djikstras_furthest(start, map.clone());
-        let res: usize = map
-            .iter()
-            .enumerate()
-            .map(|(idx, line)| inside_count_line(line, idx, &points))
-            .sum();
+        let res = super::part_two(&content);
         println!("Part Two: {}", res)
     }
 
-    const SAMPLE_2: &str = "OF----7F7F7F7F-7OOOO
-O|F--7||||||||FJOOOO
-O||OFJ||||||||L7OOOO
-FJL7L7LJLJ||LJIL-7OO
-L--JOL7IIILJS7F-7L7O
-OOOOF-JIIF7FJ|L7L7L7
-OOOOL7IF7||L7|IL7L7|
-OOOOO|FJLJ|FJ|F7|OLJ
-OOOOFJL-7O||O||||OOO
-OOOOL---JOLJOLJLJOOO";
-
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
@@ -233,4 +256,30 @@ OOOOL---JOLJOLJLJOOO";
         println!("{map:?}");
         println!("{start:?}");
     }
+
+    #[test]
+    fn loop_snapshot() {
+        let (map, start) = parse_map(SAMPLE);
+        let (_, points) = djikstras_furthest(start, map);
+        assert_eq!(
+            points.snapshot(),
+            "(0, 2)
+(0, 3)
+(1, 1)
+(1, 2)
+(1, 3)
+(2, 0)
+(2, 1)
+(2, 3)
+(2, 4)
+(3, 0)
+(3, 1)
+(3, 2)
+(3, 3)
+(3, 4)
+(4, 0)
+(4, 1)
+"
+        );
+    }
 }
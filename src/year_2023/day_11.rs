@@ -1,3 +1,5 @@
+use crate::registry::Sample;
+
 pub fn part_x(map: &Vec<Vec<char>>, expansion_factor: isize) -> usize {
     let mut empty_rows = vec![true; map.len()];
     let mut empty_columns = vec![true; map[0].len()];
@@ -50,13 +52,17 @@ pub fn parse_map(content: &str) -> Vec<Vec<char>> {
     res
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::download_day;
+pub fn part_one(content: &str) -> usize {
+    let map = parse_map(content);
+    part_x(&map, 2 - 1)
+}
 
-    use super::{parse_map, part_x};
+pub fn part_two(content: &str) -> usize {
+    let map = parse_map(content);
+    part_x(&map, 1000000 - 1)
+}
 
-    static SAMPLE: &str = "...#......
+pub const SAMPLE: &str = "...#......
 .......#..
 #.........
 ..........
@@ -67,18 +73,28 @@ mod tests {
 .......#..
 #...#.....";
 
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("374"),
+    part_two: None,
+}];
+
+#[cfg(test)]
+mod tests {
+    use crate::download_day;
+
+    use super::{parse_map, part_x, SAMPLE};
+
     #[test]
     fn part_one_test() {
         let content = download_day(2023, 11);
-        let map = parse_map(&content[..]);
-        let res = part_x(&map, 2 - 1);
+        let res = super::part_one(&content);
         println!("Part One: {res}");
     }
     #[test]
     fn part_two_test() {
         let content = download_day(2023, 11);
-        let map = parse_map(&content[..]);
-        let res = part_x(&map, 1000000 - 1);
+        let res = super::part_two(&content);
         println!("Part two: {res}");
     }
 
@@ -1,5 +1,7 @@
 use std::{collections::HashMap, usize};
 
+use crate::registry::Sample;
+
 pub fn get_arrangements(
     line: String,
     sequence: &[usize],
@@ -44,68 +46,79 @@ pub fn get_arrangements(
     return arrangements;
 }
 
+pub fn part_one(content: &str) -> usize {
+    content
+        .lines()
+        .map(|s| s.split_whitespace())
+        .map(|mut split| {
+            let line = split.next().unwrap().to_string();
+            let sequence: Vec<usize> = split
+                .next()
+                .unwrap()
+                .split(',')
+                .map(|n| n.parse().unwrap())
+                .collect();
+            (line, sequence)
+        })
+        .map(|(line, sequence)| get_arrangements(line, &sequence, 0, 0, 0, &mut HashMap::new()))
+        .sum()
+}
+
+pub fn part_two(content: &str) -> usize {
+    content
+        .lines()
+        .map(|s| s.split_whitespace())
+        .map(|mut split| {
+            let mut line: String = split.next().unwrap().to_string();
+            line = (0..5).map(|_| &line[..]).collect::<Vec<&str>>().join("?");
+            let sequence: Vec<usize> = split
+                .next()
+                .unwrap()
+                .split(',')
+                .map(|n| n.parse().unwrap())
+                .collect();
+            let sequence = sequence.repeat(5);
+            (line, sequence)
+        })
+        .map(|(line, sequence)| get_arrangements(line, &sequence, 0, 0, 0, &mut HashMap::new()))
+        .sum()
+}
+
+pub const SAMPLE: &str = "?###???????? 3,2,1
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("21"),
+    part_two: Some("525152"),
+}];
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
     use crate::download_day;
 
-    use super::get_arrangements;
+    use super::{get_arrangements, SAMPLE};
 
     #[test]
     fn part_one() {
         let content = download_day(2023, 12);
-        let res: usize = content
-            .lines()
-            .map(|s| s.split_whitespace())
-            .map(|mut split| {
-                let line = split.next().unwrap().to_string();
-                let sequence: Vec<usize> = split
-                    .next()
-                    .unwrap()
-                    .split(",")
-                    .map(|n| n.parse().unwrap())
-                    .collect();
-                (line, sequence)
-            })
-            .map(|(line, sequence)| get_arrangements(line, &sequence, 0, 0, 0, &mut HashMap::new()))
-            .sum();
+        let res = super::part_one(&content);
         println!("Part One: {res}");
     }
 
     #[test]
     fn part_two() {
         let content = download_day(2023, 12);
-        let res: usize = content
-            .lines()
-            .map(|s| (s.split_whitespace()))
-            .map(|mut split| {
-                let mut line: String = split.next().unwrap().to_string();
-                line = (0..5).map(|_| &line[..]).collect::<Vec<&str>>().join("?");
-                let sequence: Vec<usize> = split
-                    .next()
-                    .unwrap()
-                    .split(",")
-                    .map(|n| n.parse().unwrap())
-                    .collect();
-                let sequence = sequence.repeat(5);
-                (line, sequence)
-            })
-            .map(|(line, sequence)| {
-                let mut cache = HashMap::new();
-                let res = get_arrangements(line, &sequence, 0, 0, 0, &mut cache);
-                res
-            })
-            .sum();
+        let res = super::part_two(&content);
         println!("Part Two: {res}");
     }
 
-    const SAMPLE: &str = "?###???????? 3,2,1
-???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5";
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
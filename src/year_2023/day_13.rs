@@ -1,3 +1,5 @@
+use crate::registry::Sample;
+
 pub fn find_reflection(map: &[Vec<char>], differences: usize) -> usize {
     for i in 1..map.len() {
         let mut diff = 0;
@@ -35,11 +37,36 @@ pub fn find_reflection(map: &[Vec<char>], differences: usize) -> usize {
     0
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{day_13::find_reflection, download_day};
+pub fn parse_patterns(content: &str) -> Vec<Vec<Vec<char>>> {
+    let mut patterns = vec![];
+    let mut pattern = vec![];
+    for line in content.lines() {
+        if line.is_empty() {
+            patterns.push(pattern.clone());
+            pattern.clear();
+        } else {
+            pattern.push(line.chars().collect::<Vec<char>>());
+        }
+    }
+    patterns.push(pattern);
+    patterns
+}
+
+pub fn part_one(content: &str) -> usize {
+    parse_patterns(content)
+        .iter()
+        .map(|x| find_reflection(x, 0))
+        .sum()
+}
+
+pub fn part_two(content: &str) -> usize {
+    parse_patterns(content)
+        .iter()
+        .map(|x| find_reflection(x, 1))
+        .sum()
+}
 
-    const SAMPLE: &str = "#.##..##.
+pub const SAMPLE: &str = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -55,44 +82,27 @@ mod tests {
 ..##..###
 #....#..#";
 
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("405"),
+    part_two: Some("400"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::SAMPLE;
+    use crate::{download_day, year_2023::day_13::find_reflection};
+
     #[test]
     fn part_one() {
         let content = download_day(2023, 13);
-        let mut patterns = vec![];
-        let mut pattern = vec![];
-        for line in content.lines() {
-            if line.is_empty() {
-                patterns.push(pattern.clone());
-                pattern.clear();
-            } else {
-                pattern.push(line.chars().collect::<Vec<char>>());
-            }
-        }
-        patterns.push(pattern);
-        let res = patterns
-            .iter()
-            .map(|x| find_reflection(x, 0))
-            .sum::<usize>();
+        let res = super::part_one(&content);
         println!("Part One: {res}");
     }
     #[test]
     fn part_two() {
         let content = download_day(2023, 13);
-        let mut patterns = vec![];
-        let mut pattern = vec![];
-        for line in content.lines() {
-            if line.is_empty() {
-                patterns.push(pattern.clone());
-                pattern.clear();
-            } else {
-                pattern.push(line.chars().collect::<Vec<char>>());
-            }
-        }
-        patterns.push(pattern);
-        let res = patterns
-            .iter()
-            .map(|x| find_reflection(x, 1))
-            .sum::<usize>();
+        let res = super::part_two(&content);
         println!("Part Two: {res}");
     }
     #[test]
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::registry::Sample;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MapUnit {
     Round,
@@ -150,46 +152,29 @@ pub fn parse_map(content: &str) -> Vec<Vec<MapUnit>> {
     map
 }
 
-#[cfg(test)]
-mod tests {
-
-    use crate::{
-        day_14::{calculate_load, MapUnit},
-        download_day,
-    };
-
-    use super::{do_cycle, parse_map, tilt_north};
-
-    #[test]
-    fn part_one() {
-        let content = download_day(2023, 14);
-        let map = parse_map(&content[..]);
-        let (res, _) = tilt_north(&map);
-        println!("Part One: {res}");
-    }
+pub fn part_one(content: &str) -> usize {
+    let map = parse_map(content);
+    let (res, _) = tilt_north(&map);
+    res
+}
 
-    #[test]
-    fn part_two() {
-        let content = download_day(2023, 14);
-        let mut map = parse_map(&content[..]);
-        let s;
-        let mut map_vec: Vec<Vec<Vec<MapUnit>>> = vec![map.clone()];
-        loop {
-            (_, map) = do_cycle(&map);
-            if let Some(cycle_start) = map_vec.iter().position(|x| x == &map) {
-                let cycle_length = map_vec.len() - cycle_start;
-                let offset = 1000000000 - cycle_start;
-                let pos = offset % cycle_length;
-                s = calculate_load(&map_vec[cycle_start..][pos]);
-                break;
-            } else {
-                map_vec.push(map.clone());
-            }
+pub fn part_two(content: &str) -> usize {
+    let mut map = parse_map(content);
+    let mut map_vec: Vec<Vec<Vec<MapUnit>>> = vec![map.clone()];
+    loop {
+        (_, map) = do_cycle(&map);
+        if let Some(cycle_start) = map_vec.iter().position(|x| x == &map) {
+            let cycle_length = map_vec.len() - cycle_start;
+            let offset = 1000000000 - cycle_start;
+            let pos = offset % cycle_length;
+            return calculate_load(&map_vec[cycle_start..][pos]);
+        } else {
+            map_vec.push(map.clone());
         }
-        println!("Part Two: {s}");
     }
+}
 
-    const SAMPLE: &str = "O....#....
+pub const SAMPLE: &str = "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -200,7 +185,7 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-    const SAMPLE_2: &str = ".....#....
+pub const SAMPLE_2: &str = ".....#....
 ....#...O#
 .....##...
 ..O#......
@@ -211,6 +196,36 @@ O.#..O.#.#
 #...O###.O
 #.OOO#...O";
 
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("136"),
+    part_two: Some("64"),
+}];
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        download_day,
+        year_2023::day_14::{calculate_load, MapUnit},
+    };
+
+    use super::{do_cycle, parse_map, tilt_north, SAMPLE, SAMPLE_2};
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 14);
+        let res = super::part_one(&content);
+        println!("Part One: {res}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 14);
+        let s = super::part_two(&content);
+        println!("Part Two: {s}");
+    }
+
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use crate::registry::Sample;
 
 #[derive(Debug, Clone, Copy, Hash)]
 pub struct Lens<'a> {
@@ -8,7 +10,7 @@ pub struct Lens<'a> {
 
 pub fn holiday_ascii_string_helper_manual_arrangement_procedure<'a>(
     content: &'a str,
-    map: &mut HashMap<usize, Vec<Lens<'a>>>,
+    map: &mut BTreeMap<usize, Vec<Lens<'a>>>,
 ) {
     if content.contains("=") {
         let mut splitter = content.split("=");
@@ -50,20 +52,63 @@ pub fn holiday_ascii_string_helper(content: &str) -> usize {
     total
 }
 
+pub fn part_one(content: &str) -> usize {
+    content
+        .split(',')
+        .map(|x| holiday_ascii_string_helper(x.trim()))
+        .sum()
+}
+
+pub fn arrange(content: &str) -> BTreeMap<usize, Vec<Lens<'_>>> {
+    let mut map = BTreeMap::new();
+    content.split(',').for_each(|x| {
+        holiday_ascii_string_helper_manual_arrangement_procedure(x.trim(), &mut map);
+    });
+    map
+}
+
+pub fn part_two(content: &str) -> usize {
+    let map = arrange(content);
+    let mut total = 0;
+    for (box_num, lens_list) in map {
+        for (idx, lens) in lens_list.iter().enumerate() {
+            total += (idx + 1) * lens.value * (box_num + 1);
+        }
+    }
+    total
+}
+
+pub const SAMPLE: &str = "HASH";
+
+pub const SAMPLE_2: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+pub const SAMPLES: &[Sample] = &[
+    Sample {
+        input: SAMPLE,
+        part_one: Some("52"),
+        part_two: None,
+    },
+    Sample {
+        input: SAMPLE_2,
+        part_one: Some("1320"),
+        part_two: Some("145"),
+    },
+];
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::BTreeMap;
 
-    use crate::{day_15::holiday_ascii_string_helper, download_day};
+    use crate::{download_day, year_2023::day_15::holiday_ascii_string_helper};
 
-    use super::holiday_ascii_string_helper_manual_arrangement_procedure;
+    use super::{
+        arrange, holiday_ascii_string_helper_manual_arrangement_procedure, SAMPLE, SAMPLE_2,
+    };
+    use crate::snapshot::Snapshot;
 
     #[test]
     fn part_one() {
-        let res = download_day(2023, 15)
-            .split(",")
-            .map(|x| holiday_ascii_string_helper(x.trim()))
-            .sum::<usize>();
+        let res = super::part_one(&download_day(2023, 15));
 
         println!("Part One: {res}")
     }
@@ -71,21 +116,10 @@ mod tests {
     #[test]
     fn part_two() {
         let content = download_day(2023, 15);
-        let mut map = HashMap::new();
-        content.split(",").for_each(|x| {
-            holiday_ascii_string_helper_manual_arrangement_procedure(x.trim(), &mut map);
-        });
-        let mut total = 0;
-        for (box_num, lens_list) in map {
-            for (idx, lens) in lens_list.iter().enumerate() {
-                total += (idx + 1) * lens.value * (box_num + 1);
-            }
-        }
+        let total = super::part_two(&content);
         println!("Part Two: {total}");
     }
 
-    const SAMPLE: &str = "HASH";
-    const SAMPLE_2: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
     #[test]
     fn part_one_sample() {
         assert_eq!(
@@ -98,7 +132,7 @@ mod tests {
     }
     #[test]
     fn part_two_sample() {
-        let mut map = HashMap::new();
+        let mut map = BTreeMap::new();
         let content = SAMPLE_2;
         content.split(",").for_each(|x| {
             holiday_ascii_string_helper_manual_arrangement_procedure(x.trim(), &mut map);
@@ -116,4 +150,15 @@ mod tests {
     fn hash_sample() {
         assert_eq!(holiday_ascii_string_helper(SAMPLE), 52);
     }
+
+    #[test]
+    fn arrangement_snapshot() {
+        assert_eq!(
+            arrange(SAMPLE_2).snapshot(),
+            "0: [Lens { label: \"rn\", value: 1 }, Lens { label: \"cm\", value: 2 }]
+1: []
+3: [Lens { label: \"ot\", value: 7 }, Lens { label: \"ab\", value: 5 }, Lens { label: \"pc\", value: 6 }]
+"
+        );
+    }
 }
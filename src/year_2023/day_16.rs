@@ -1,4 +1,9 @@
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Display,
+};
+
+use crate::{registry::Sample, repl::Explore};
 
 type Point = (isize, isize);
 #[derive(Clone, Copy, Debug)]
@@ -9,6 +14,18 @@ pub enum MapUnit {
     LeftMirror,
     Empty,
 }
+
+impl Display for MapUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            MapUnit::VerticalSplitter => write!(f, "|"),
+            MapUnit::HorizontalSplitter => write!(f, "-"),
+            MapUnit::RightMirror => write!(f, "/"),
+            MapUnit::LeftMirror => write!(f, "\\"),
+            MapUnit::Empty => write!(f, "."),
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Direction {
     North,
@@ -26,7 +43,7 @@ impl Direction {
         }
     }
 }
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub struct Beam {
     location: Point,
     direction: Direction,
@@ -52,7 +69,7 @@ pub fn parse_map(content: &str) -> Vec<Vec<MapUnit>> {
     res
 }
 
-pub fn simulate_beams(map: &[Vec<MapUnit>], mut beams: Vec<Beam>) -> usize {
+pub fn energized_tiles(map: &[Vec<MapUnit>], mut beams: Vec<Beam>) -> BTreeSet<Point> {
     let mut new_beams: Vec<Beam> = vec![];
     let mut visited: HashSet<Point> = HashSet::new();
     let mut unique_beam = HashSet::new();
@@ -119,75 +136,167 @@ pub fn simulate_beams(map: &[Vec<MapUnit>], mut beams: Vec<Beam>) -> usize {
         std::mem::swap(&mut beams, &mut new_beams);
         new_beams.clear();
     }
-    visited.len() - 1
+    visited
+        .into_iter()
+        .filter(|&(x, y)| x >= 0 && y >= 0 && x < map[0].len() as isize && y < map.len() as isize)
+        .collect()
+}
+
+pub fn simulate_beams(map: &[Vec<MapUnit>], beams: Vec<Beam>) -> usize {
+    energized_tiles(map, beams).len()
+}
+
+pub struct Contraption {
+    map: Vec<Vec<MapUnit>>,
+}
+
+impl Contraption {
+    pub fn render(&self, energized: &BTreeSet<Point>) -> String {
+        let mut res = String::new();
+        for (y, row) in self.map.iter().enumerate() {
+            for (x, unit) in row.iter().enumerate() {
+                if energized.contains(&(x as isize, y as isize)) {
+                    res.push('#');
+                } else {
+                    res.push_str(&unit.to_string());
+                }
+            }
+            res.push('\n');
+        }
+        res
+    }
 }
 
+impl Explore for Contraption {
+    fn show(&self) -> String {
+        self.render(&BTreeSet::new())
+    }
+
+    fn query(&self, command: &str, args: &[&str]) -> Option<String> {
+        match (command, args) {
+            ("energize", [x, y, direction]) => {
+                let direction = match *direction {
+                    "north" => Direction::North,
+                    "east" => Direction::East,
+                    "south" => Direction::South,
+                    "west" => Direction::West,
+                    _ => return Some(format!("Unknown direction {direction}")),
+                };
+                let beam = Beam {
+                    location: (x.parse().ok()?, y.parse().ok()?),
+                    direction,
+                };
+                let energized = energized_tiles(&self.map, vec![beam]);
+                Some(format!(
+                    "{beam:?}\n{}{} tiles energized",
+                    self.render(&energized),
+                    energized.len()
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn commands(&self) -> &'static [&'static str] {
+        &["energize <x> <y> <north|east|south|west>"]
+    }
+}
+
+pub fn explore(content: &str) -> Box<dyn Explore + '_> {
+    Box::new(Contraption {
+        map: parse_map(content),
+    })
+}
+
+pub fn part_one(content: &str) -> usize {
+    let map = parse_map(content);
+    simulate_beams(
+        &map,
+        vec![Beam {
+            location: (-1, 0),
+            direction: Direction::East,
+        }],
+    )
+}
+
+pub fn part_two(content: &str) -> usize {
+    let map = parse_map(content);
+    let mut beams = vec![];
+    beams.append(
+        &mut (0..map.len() as isize)
+            .map(|x| Beam {
+                location: (-1, x),
+                direction: Direction::East,
+            })
+            .collect(),
+    );
+    beams.append(
+        &mut (0..map.len() as isize)
+            .map(|x| Beam {
+                location: (map[0].len() as isize, x),
+                direction: Direction::West,
+            })
+            .collect(),
+    );
+    beams.append(
+        &mut (0..map[0].len() as isize)
+            .map(|x| Beam {
+                location: (x, -1),
+                direction: Direction::South,
+            })
+            .collect(),
+    );
+    beams.append(
+        &mut (0..map[0].len() as isize)
+            .map(|x| Beam {
+                location: (x, map.len() as isize),
+                direction: Direction::South,
+            })
+            .collect(),
+    );
+    beams
+        .iter()
+        .map(|b| simulate_beams(&map, vec![*b]))
+        .max()
+        .unwrap()
+}
+
+pub const SAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("46"),
+    part_two: Some("51"),
+}];
+
 #[cfg(test)]
 mod tests {
-    use crate::download_day;
+    use crate::{download_day, snapshot::Snapshot};
 
-    use super::{parse_map, simulate_beams, Beam, Direction};
+    use super::{energized_tiles, parse_map, simulate_beams, Beam, Direction, SAMPLE};
 
     #[test]
     fn part_one() {
         let content = download_day(2023, 16);
-        let map = parse_map(&content);
-        let res = simulate_beams(
-            &map,
-            vec![Beam {
-                location: (-1, 0),
-                direction: Direction::East,
-            }],
-        );
+        let res = super::part_one(&content);
         println!("Part One: {res}");
     }
     #[test]
     fn part_two() {
         let content = download_day(2023, 16);
-        let map = parse_map(&content);
-        let mut beams = vec![];
-        beams.append(
-            &mut (0..map.len() as isize)
-                .map(|x| Beam {
-                    location: (-1, x),
-                    direction: Direction::East,
-                })
-                .collect(),
-        );
-        beams.append(
-            &mut (0..map.len() as isize)
-                .map(|x| Beam {
-                    location: (map[0].len() as isize, x),
-                    direction: Direction::West,
-                })
-                .collect(),
-        );
-        beams.append(
-            &mut (0..map[0].len() as isize)
-                .map(|x| Beam {
-                    location: (x, -1),
-                    direction: Direction::South,
-                })
-                .collect(),
-        );
-        beams.append(
-            &mut (0..map[0].len() as isize)
-                .map(|x| Beam {
-                    location: (x, map.len() as isize),
-                    direction: Direction::South,
-                })
-                .collect(),
-        );
-        let res = beams
-            .iter()
-            .map(|b| simulate_beams(&map, vec![b.clone()]))
-            .max()
-            .unwrap();
+        let res = super::part_two(&content);
         println!("Part Two: {res}");
     }
 
-    const SAMPLE: &str = include_str!("../day16sample.txt");
-
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
@@ -246,4 +355,63 @@ mod tests {
             .unwrap();
         assert_eq!(res, 51);
     }
+
+    #[test]
+    fn energized_snapshot() {
+        let map = parse_map(SAMPLE);
+        let beams = vec![Beam {
+            location: (-1, 0),
+            direction: Direction::East,
+        }];
+        assert_eq!(
+            energized_tiles(&map, beams).snapshot(),
+            "(0, 0)
+(0, 7)
+(1, 0)
+(1, 1)
+(1, 2)
+(1, 3)
+(1, 4)
+(1, 5)
+(1, 6)
+(1, 7)
+(1, 8)
+(1, 9)
+(2, 0)
+(2, 7)
+(2, 8)
+(3, 0)
+(3, 7)
+(3, 8)
+(4, 0)
+(4, 6)
+(4, 7)
+(4, 8)
+(5, 0)
+(5, 1)
+(5, 2)
+(5, 3)
+(5, 4)
+(5, 5)
+(5, 6)
+(5, 7)
+(5, 8)
+(5, 9)
+(6, 2)
+(6, 3)
+(6, 4)
+(6, 5)
+(6, 6)
+(6, 7)
+(6, 8)
+(7, 2)
+(7, 6)
+(7, 7)
+(7, 8)
+(7, 9)
+(8, 2)
+(9, 2)
+"
+        );
+    }
 }
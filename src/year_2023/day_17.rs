@@ -3,6 +3,8 @@ use std::{
     collections::{BinaryHeap, HashSet},
 };
 
+use crate::registry::Sample;
+
 pub type Point = (isize, isize);
 
 const ALL_DIRECTIONS: [Direction; 4] = [
@@ -100,29 +102,17 @@ pub fn parse_map(content: &str) -> Vec<Vec<usize>> {
     res
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::download_day;
-
-    use super::{djikstras, parse_map};
-
-    #[test]
-    fn part_one() {
-        let content = download_day(2023, 17);
-        let map = parse_map(&content);
-        let res = djikstras(&map, 1, 3);
-        println!("Part One: {res}");
-    }
+pub fn part_one(content: &str) -> usize {
+    let map = parse_map(content);
+    djikstras(&map, 1, 3)
+}
 
-    #[test]
-    fn part_two() {
-        let content = download_day(2023, 17);
-        let map = parse_map(&content);
-        let res = djikstras(&map, 4, 10);
-        println!("Part Two: {res}");
-    }
+pub fn part_two(content: &str) -> usize {
+    let map = parse_map(content);
+    djikstras(&map, 4, 10)
+}
 
-    const SAMPLE: &str = "2413432311323
+pub const SAMPLE: &str = "2413432311323
 3215453535623
 3255245654254
 3446585845452
@@ -136,12 +126,45 @@ mod tests {
 2546548887735
 4322674655533";
 
-    const SAMPLE_2: &str = "111111111111
+pub const SAMPLE_2: &str = "111111111111
 999999999991
 999999999991
 999999999991
 999999999991";
 
+pub const SAMPLES: &[Sample] = &[
+    Sample {
+        input: SAMPLE,
+        part_one: Some("102"),
+        part_two: Some("94"),
+    },
+    Sample {
+        input: SAMPLE_2,
+        part_one: None,
+        part_two: Some("71"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::download_day;
+
+    use super::{djikstras, parse_map, SAMPLE, SAMPLE_2};
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 17);
+        let res = super::part_one(&content);
+        println!("Part One: {res}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 17);
+        let res = super::part_two(&content);
+        println!("Part Two: {res}");
+    }
+
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
@@ -1,3 +1,5 @@
+use crate::registry::Sample;
+
 pub type Point = (isize, isize);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -161,29 +163,17 @@ pub fn find_area(instructions: &[DigInstruction]) -> isize {
     edges.len() as isize + total
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{day_18::find_area, download_day};
-
-    use super::{parse_hex_plan, parse_plan};
-
-    #[test]
-    fn part_one() {
-        let content = download_day(2023, 18);
-        let plan = parse_plan(&content);
-        let res = find_area(&plan);
-        println!("Part One: {res}");
-    }
+pub fn part_one(content: &str) -> isize {
+    let plan = parse_plan(content);
+    find_area(&plan)
+}
 
-    #[test]
-    fn part_two() {
-        let content = download_day(2023, 18);
-        let plan = parse_hex_plan(&content);
-        let res = find_area(&plan);
-        println!("Part One: {res}");
-    }
+pub fn part_two(content: &str) -> isize {
+    let plan = parse_hex_plan(content);
+    find_area(&plan)
+}
 
-    const SAMPLE: &str = "R 6 (#70c710)
+pub const SAMPLE: &str = "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
 D 2 (#d2c081)
@@ -198,6 +188,32 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
 
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("62"),
+    part_two: Some("952408144115"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use crate::{download_day, year_2023::day_18::find_area};
+
+    use super::{parse_hex_plan, parse_plan, SAMPLE};
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 18);
+        let res = super::part_one(&content);
+        println!("Part One: {res}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 18);
+        let res = super::part_two(&content);
+        println!("Part One: {res}");
+    }
+
     #[test]
     fn part_one_sample() {
         let content = SAMPLE;
@@ -0,0 +1,452 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::{regex, registry::Sample, repl::Explore};
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Draw {
+    pub cubes: BTreeMap<String, usize>,
+}
+
+impl Draw {
+    pub fn new<S: Into<String>>(cubes: impl IntoIterator<Item = (S, usize)>) -> Self {
+        Draw {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, amount)| (colour.into(), amount))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, colour: &str) -> usize {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Bag {
+    cubes: BTreeMap<String, usize>,
+}
+
+impl Bag {
+    pub fn new<S: Into<String>>(cubes: impl IntoIterator<Item = (S, usize)>) -> Self {
+        Bag {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, amount)| (colour.into(), amount))
+                .collect(),
+        }
+    }
+
+    pub fn standard() -> Self {
+        Bag::new([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    pub fn get(&self, colour: &str) -> usize {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(|colour| colour.as_str())
+    }
+
+    pub fn fits(&self, draw: &Draw) -> bool {
+        draw.cubes
+            .iter()
+            .all(|(colour, amount)| *amount <= self.get(colour))
+    }
+
+    pub fn is_possible(&self, game: &Game) -> bool {
+        game.impossible_round(self).is_none()
+    }
+
+    pub fn minimum(game: &Game) -> Bag {
+        let mut cubes: BTreeMap<String, usize> = BTreeMap::new();
+        for draw in &game.rounds {
+            for (colour, amount) in &draw.cubes {
+                let entry = cubes.entry(colour.clone()).or_default();
+                *entry = (*entry).max(*amount);
+            }
+        }
+        Bag { cubes }
+    }
+
+    pub fn minimum_for(&self, game: &Game) -> Bag {
+        let mut minimum = Bag::minimum(game);
+        for colour in self.cubes.keys() {
+            minimum.cubes.entry(colour.clone()).or_default();
+        }
+        minimum
+    }
+
+    pub fn power(&self) -> usize {
+        self.cubes.values().product()
+    }
+}
+
+impl FromStr for Bag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s
+            .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+            .filter(|word| !word.is_empty())
+            .collect();
+        if !words.len().is_multiple_of(2) {
+            return Err(format!("Expected amount and colour pairs in {s:?}"));
+        }
+        let mut cubes = BTreeMap::new();
+        for pair in words.chunks(2) {
+            let amount: usize = pair[0]
+                .parse()
+                .map_err(|_| format!("Expected a number found {}", pair[0]))?;
+            cubes.insert(pair[1].to_string(), amount);
+        }
+        Ok(Bag { cubes })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Game {
+    pub id: usize,
+    pub rounds: Vec<Draw>,
+}
+
+impl Game {
+    pub fn summary(&self) -> Bag {
+        Bag::minimum(self)
+    }
+
+    pub fn round_count(&self) -> usize {
+        self.rounds.len()
+    }
+
+    pub fn impossible_round(&self, bag: &Bag) -> Option<usize> {
+        self.rounds.iter().position(|draw| !bag.fits(draw))
+    }
+}
+
+pub fn cube_counter_regex(line: &str) -> Game {
+    let id: usize = regex!(r"Game (\d+):")
+        .captures(line)
+        .unwrap()
+        .expect("Expected Id")[1]
+        .parse()
+        .expect("Expected number");
+
+    let (_, rounds) = line.split_once(':').expect("Expected rounds");
+    let re = regex!(r"(\d+) (\w+)");
+    let rounds = rounds
+        .split(';')
+        .map(|round| {
+            let mut draw = Draw::default();
+            for m in re.captures_iter(round) {
+                let m = m.unwrap();
+                let amount: usize = m[1].parse().expect("Expected amount");
+                draw.cubes.insert(m[2].to_string(), amount);
+            }
+            draw
+        })
+        .collect();
+
+    Game { id, rounds }
+}
+
+pub fn cube_counter(line: &str) -> Game {
+    let mut iter = line.split(" ").into_iter();
+    let mut id: usize = 0;
+    let mut rounds = vec![];
+    let mut draw = Draw::default();
+    if let Some(_) = iter.next() {
+        if let Some(id_string) = iter.next() {
+            id = id_string[..id_string.len() - 1]
+                .parse()
+                .expect("Expected ID number");
+        }
+    }
+    let mut iter = iter.peekable();
+    while let Some(amount) = iter.next() {
+        let n: usize = amount.parse().expect("Expected number");
+        if let Some(mut color) = iter.next() {
+            let mut end_of_round = true;
+            if iter.peek().is_some() {
+                end_of_round = color.ends_with(';');
+                color = &color[..color.len() - 1];
+            }
+            draw.cubes.insert(color.to_string(), n);
+            if end_of_round {
+                rounds.push(std::mem::take(&mut draw));
+            }
+        }
+    }
+    Game { id, rounds }
+}
+
+pub fn possible_games(content: &str, bag: &Bag) -> usize {
+    content
+        .lines()
+        .map(cube_counter)
+        .filter(|game| bag.is_possible(game))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn minimum_power(content: &str, bag: &Bag) -> usize {
+    content
+        .lines()
+        .map(cube_counter)
+        .map(|game| bag.minimum_for(&game).power())
+        .sum()
+}
+
+pub fn part_one(content: &str) -> usize {
+    possible_games(content, &Bag::standard())
+}
+
+pub fn part_two(content: &str) -> usize {
+    minimum_power(content, &Bag::standard())
+}
+
+pub struct Games {
+    games: Vec<Game>,
+    bag: Bag,
+}
+
+impl Explore for Games {
+    fn show(&self) -> String {
+        self.games
+            .iter()
+            .map(|game| format!("{game:?}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn query(&self, command: &str, args: &[&str]) -> Option<String> {
+        match (command, args) {
+            ("game", [id]) => {
+                let id: usize = id.parse().ok()?;
+                match self.games.iter().find(|game| game.id == id) {
+                    Some(game) => Some(format!(
+                        "{game:?}\n{} rounds, {:?}",
+                        game.round_count(),
+                        game.summary()
+                    )),
+                    None => Some(format!("No game with id {id}")),
+                }
+            }
+            ("possible", args) => {
+                let bag = match args {
+                    [] => self.bag.clone(),
+                    args => match args.join(" ").parse() {
+                        Ok(bag) => bag,
+                        Err(err) => return Some(err),
+                    },
+                };
+                Some(
+                    self.games
+                        .iter()
+                        .filter(|game| bag.is_possible(game))
+                        .map(|game| game.id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )
+            }
+            ("impossible", []) => Some(
+                self.games
+                    .iter()
+                    .filter_map(|game| {
+                        let round = game.impossible_round(&self.bag)?;
+                        Some(format!(
+                            "Game {} round {}: {:?}",
+                            game.id,
+                            round + 1,
+                            game.rounds[round]
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn commands(&self) -> &'static [&'static str] {
+        &["game <id>", "possible [<amount> <colour>...]", "impossible"]
+    }
+}
+
+pub fn explore(content: &str) -> Box<dyn Explore + '_> {
+    Box::new(Games {
+        games: content.lines().map(cube_counter).collect(),
+        bag: Bag::standard(),
+    })
+}
+
+pub const SAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("8"),
+    part_two: Some("2286"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        download_day, timeit,
+        year_2023::day_2::{cube_counter, cube_counter_regex, minimum_power, possible_games},
+        TestRng,
+    };
+
+    use super::{Bag, Draw, Game, SAMPLE};
+
+    #[test]
+    fn part_1() {
+        let content = download_day(2023, 2);
+        let result = super::part_one(&content);
+        println!("Part 1: {result}")
+    }
+
+    #[test]
+    fn part_2() {
+        let content = download_day(2023, 2);
+        let result = super::part_two(&content);
+
+        println!("Part 2: {result}")
+    }
+
+    #[test]
+    fn part_1_regex() {
+        let content = download_day(2023, 2);
+        let result: usize = content
+            .lines()
+            .map(cube_counter_regex)
+            .filter(|game| Bag::standard().is_possible(game))
+            .map(|game| game.id)
+            .sum();
+        println!("Part 1: {result}")
+    }
+
+    #[test]
+    fn part_2_regex() {
+        let content = download_day(2023, 2);
+        let result: usize = content
+            .lines()
+            .map(cube_counter_regex)
+            .map(|game| Bag::standard().minimum_for(&game).power())
+            .sum();
+
+        println!("Part 2: {result}")
+    }
+
+    #[test]
+    fn cube_counter_test() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let expected = Bag::new([("blue", 6), ("red", 4), ("green", 2)]);
+        assert_eq!(cube_counter(line).summary(), expected);
+        assert_eq!(cube_counter_regex(line).summary(), expected);
+    }
+
+    #[test]
+    fn multiply_test() {
+        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let game = cube_counter(line);
+        assert_eq!(Bag::minimum(&game).power(), 1560)
+    }
+
+    #[test]
+    fn rounds_are_kept() {
+        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let expected = Game {
+            id: 3,
+            rounds: vec![
+                Draw::new([("green", 8), ("blue", 6), ("red", 20)]),
+                Draw::new([("blue", 5), ("red", 4), ("green", 13)]),
+                Draw::new([("green", 5), ("red", 1)]),
+            ],
+        };
+        assert_eq!(cube_counter(line), expected);
+        assert_eq!(cube_counter_regex(line), expected);
+        assert_eq!(expected.round_count(), 3);
+        assert_eq!(expected.impossible_round(&Bag::standard()), Some(0));
+    }
+
+    #[test]
+    fn parsers_agree_on_sample() {
+        for line in SAMPLE.lines() {
+            assert_eq!(cube_counter(line), cube_counter_regex(line));
+        }
+        let impossible: Vec<_> = SAMPLE
+            .lines()
+            .map(cube_counter)
+            .map(|game| (game.id, game.impossible_round(&Bag::standard())))
+            .collect();
+        assert_eq!(
+            impossible,
+            [(1, None), (2, None), (3, Some(0)), (4, Some(2)), (5, None)]
+        );
+    }
+
+    #[test]
+    fn arbitrary_colours() {
+        let line = "Game 7: 2 yellow, 1 red; 3 purple; 1 yellow, 4 red";
+        let game = cube_counter(line);
+        assert_eq!(game, cube_counter_regex(line));
+        assert_eq!(game.round_count(), 3);
+        assert_eq!(
+            Bag::minimum(&game),
+            Bag::new([("yellow", 2), ("red", 4), ("purple", 3)])
+        );
+        assert_eq!(Bag::minimum(&game).power(), 24);
+        assert_eq!(Bag::standard().minimum_for(&game).power(), 0);
+
+        let bag: Bag = "4 red, 2 yellow, 2 purple".parse().unwrap();
+        assert_eq!(game.impossible_round(&bag), Some(1));
+        assert!(!bag.is_possible(&game));
+        let bag: Bag = "4 red 2 yellow 3 purple".parse().unwrap();
+        assert!(bag.is_possible(&game));
+        assert!("4 red 2".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn configurable_bag() {
+        assert_eq!(possible_games(SAMPLE, &Bag::standard()), 8);
+        let bag = Bag::new([("red", 20), ("green", 13), ("blue", 15)]);
+        assert_eq!(possible_games(SAMPLE, &bag), 15);
+        assert_eq!(minimum_power(SAMPLE, &Bag::standard()), 2286);
+    }
+
+    #[test]
+    fn parser_benchmark() {
+        let colours = ["red", "green", "blue", "yellow"];
+        let mut rng = TestRng::new(7);
+        let content: Vec<String> = (1..=20_000)
+            .map(|id| {
+                let rounds: Vec<String> = (0..1 + rng.below(6))
+                    .map(|_| {
+                        let draws: Vec<String> = colours
+                            .iter()
+                            .take(1 + rng.below(4))
+                            .map(|colour| format!("{} {colour}", 1 + rng.below(20)))
+                            .collect();
+                        draws.join(", ")
+                    })
+                    .collect();
+                format!("Game {id}: {}", rounds.join("; "))
+            })
+            .collect();
+
+        let hand_written: Vec<Game> =
+            timeit(|| content.iter().map(|line| cube_counter(line)).collect());
+        let regex: Vec<Game> = timeit(|| {
+            content
+                .iter()
+                .map(|line| cube_counter_regex(line))
+                .collect()
+        });
+        assert_eq!(hand_written, regex);
+    }
+}
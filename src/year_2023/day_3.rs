@@ -0,0 +1,287 @@
+use std::{collections::BTreeMap, ops::Range, vec};
+
+use crate::registry::Sample;
+
+type Point = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: usize,
+    pub row: usize,
+    pub col_span: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub ch: char,
+    pub pos: Point,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    number_symbols: Vec<Vec<usize>>,
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(content: &str) -> Self {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+        for (row, line) in content.lines().enumerate() {
+            let mut buffer = String::new();
+            let mut start = 0;
+            for (col, c) in line.chars().chain(std::iter::once('.')).enumerate() {
+                if c.is_ascii_digit() {
+                    if buffer.is_empty() {
+                        start = col;
+                    }
+                    buffer.push(c);
+                    continue;
+                }
+                if !buffer.is_empty() {
+                    numbers.push(PartNumber {
+                        value: buffer.parse().unwrap(),
+                        row,
+                        col_span: start..col,
+                    });
+                    buffer.clear();
+                }
+                if c != '.' {
+                    symbols.push(Symbol {
+                        ch: c,
+                        pos: (row, col),
+                    });
+                }
+            }
+        }
+
+        let positions: BTreeMap<Point, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(idx, symbol)| (symbol.pos, idx))
+            .collect();
+        let mut number_symbols = vec![vec![]; numbers.len()];
+        let mut symbol_numbers = vec![vec![]; symbols.len()];
+        for (number_idx, number) in numbers.iter().enumerate() {
+            for row in number.row.saturating_sub(1)..=number.row + 1 {
+                for col in number.col_span.start.saturating_sub(1)..=number.col_span.end {
+                    if let Some(&symbol_idx) = positions.get(&(row, col)) {
+                        number_symbols[number_idx].push(symbol_idx);
+                        symbol_numbers[symbol_idx].push(number_idx);
+                    }
+                }
+            }
+        }
+        for adjacent in symbol_numbers.iter_mut() {
+            adjacent.sort();
+        }
+
+        Schematic {
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        }
+    }
+
+    pub fn numbers(&self) -> &[PartNumber] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn symbol_at(&self, pos: Point) -> Option<usize> {
+        self.symbols.iter().position(|symbol| symbol.pos == pos)
+    }
+
+    pub fn numbers_adjacent_to(&self, symbol: usize) -> impl Iterator<Item = &PartNumber> {
+        self.symbol_numbers[symbol]
+            .iter()
+            .map(|&idx| &self.numbers[idx])
+    }
+
+    pub fn symbols_adjacent_to(&self, number: usize) -> impl Iterator<Item = &Symbol> {
+        self.number_symbols[number]
+            .iter()
+            .map(|&idx| &self.symbols[idx])
+    }
+
+    pub fn part_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+
+    pub fn apply_rule<T>(&self, ch: char, count: usize, combine: impl Fn(&[usize]) -> T) -> Vec<T> {
+        self.symbols
+            .iter()
+            .zip(&self.symbol_numbers)
+            .filter(|(symbol, numbers)| symbol.ch == ch && numbers.len() == count)
+            .map(|(_, numbers)| {
+                let values: Vec<usize> =
+                    numbers.iter().map(|&idx| self.numbers[idx].value).collect();
+                combine(&values)
+            })
+            .collect()
+    }
+
+    pub fn isolated_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+}
+
+pub fn get_gears(content: &str) -> Vec<(usize, usize)> {
+    Schematic::parse(content).apply_rule('*', 2, |values| (values[0], values[1]))
+}
+
+pub fn get_part_numbers(content: &str) -> Vec<usize> {
+    Schematic::parse(content)
+        .part_numbers()
+        .map(|number| number.value)
+        .collect()
+}
+
+pub fn part_one(content: &str) -> usize {
+    get_part_numbers(content).iter().sum()
+}
+
+pub fn part_two(content: &str) -> usize {
+    get_gears(content).iter().map(|(a, b)| a * b).sum()
+}
+
+pub const SAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("4361"),
+    part_two: Some("467835"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::{PartNumber, Schematic, Symbol, SAMPLE};
+    use crate::{
+        download_day,
+        year_2023::day_3::{get_gears, get_part_numbers},
+    };
+
+    #[test]
+    fn part_2() {
+        let content = download_day(2023, 3);
+        let result = super::part_two(&content);
+        println!("{result}");
+    }
+
+    #[test]
+    fn part_1() {
+        let content = download_day(2023, 3);
+        let result = super::part_one(&content);
+        println!("part 1: {result}");
+    }
+    #[test]
+    fn sample_input() {
+        let result = get_part_numbers(SAMPLE);
+        assert_eq!(result.iter().sum::<usize>(), 4361);
+    }
+    #[test]
+    fn sample_input_two() {
+        let result = get_gears(SAMPLE);
+        assert_eq!(result.iter().map(|(a, b)| a * b).sum::<usize>(), 467835);
+    }
+
+    #[test]
+    fn gears_are_ordered() {
+        assert_eq!(get_gears(SAMPLE), vec![(467, 35), (755, 598)]);
+    }
+
+    #[test]
+    fn number_touching_two_gears() {
+        let content = "1*.\n.2.\n.*3";
+        assert_eq!(get_gears(content), vec![(1, 2), (2, 3)]);
+        assert_eq!(super::part_two(content), 8);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let schematic = Schematic::parse(SAMPLE);
+        let sums = schematic.apply_rule('*', 1, |values| values.iter().sum::<usize>());
+        assert_eq!(sums, [617]);
+        let values = schematic.apply_rule('#', 1, |values| values[0]);
+        assert_eq!(values, [633]);
+        assert!(schematic
+            .apply_rule('*', 3, |values| values.len())
+            .is_empty());
+    }
+
+    #[test]
+    fn schematic_adjacency() {
+        let schematic = Schematic::parse(SAMPLE);
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(schematic.symbols().len(), 6);
+
+        let star = schematic.symbol_at((1, 3)).unwrap();
+        let values: Vec<_> = schematic
+            .numbers_adjacent_to(star)
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, [467, 35]);
+
+        let idx = schematic
+            .numbers()
+            .iter()
+            .position(|number| number.value == 617)
+            .unwrap();
+        assert_eq!(
+            schematic.numbers()[idx],
+            PartNumber {
+                value: 617,
+                row: 4,
+                col_span: 0..3
+            }
+        );
+        let symbols: Vec<_> = schematic.symbols_adjacent_to(idx).collect();
+        assert_eq!(
+            symbols,
+            [&Symbol {
+                ch: '*',
+                pos: (4, 3)
+            }]
+        );
+
+        let isolated: Vec<_> = schematic
+            .isolated_numbers()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(isolated, [114, 58]);
+    }
+
+    #[test]
+    fn numbers_at_line_end() {
+        let schematic = Schematic::parse("..12\n...#\n34..\n");
+        let values: Vec<_> = schematic
+            .part_numbers()
+            .map(|number| (number.value, number.col_span.clone()))
+            .collect();
+        assert_eq!(values, [(12, 2..4)]);
+        assert_eq!(schematic.isolated_numbers().count(), 1);
+    }
+}
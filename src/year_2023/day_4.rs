@@ -0,0 +1,285 @@
+use std::{collections::BTreeSet, error::Error, fmt::Display};
+
+use num::{traits::CheckedAdd, One, Zero};
+
+use crate::registry::Sample;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: usize,
+    pub winning: BTreeSet<usize>,
+    pub picked: Vec<usize>,
+}
+
+impl Card {
+    pub fn parse(line: &str) -> Card {
+        let (header, numbers) = line.split_once(":").expect("expected card header");
+        let id = header
+            .split_whitespace()
+            .nth(1)
+            .expect("expected card id")
+            .parse()
+            .expect("expected number");
+        let (winning, picked) = numbers.split_once("|").expect("expected picked numbers");
+        Card {
+            id,
+            winning: winning
+                .split_whitespace()
+                .map(|num| num.parse().expect("expected number"))
+                .collect(),
+            picked: picked
+                .split_whitespace()
+                .map(|num| num.parse().expect("expected number"))
+                .collect(),
+        }
+    }
+
+    pub fn matching(&self) -> usize {
+        self.picked
+            .iter()
+            .filter(|num| self.winning.contains(num))
+            .count()
+    }
+}
+
+pub fn parse_cards(content: &str) -> Vec<Card> {
+    content.lines().map(Card::parse).collect()
+}
+
+pub fn doubling(matching: usize) -> usize {
+    if matching == 0 {
+        0
+    } else {
+        1 << (matching - 1)
+    }
+}
+
+pub fn linear(matching: usize) -> usize {
+    matching
+}
+
+pub fn total_score(cards: &[Card], scoring: impl Fn(usize) -> usize) -> usize {
+    cards.iter().map(|card| scoring(card.matching())).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spawn<T = usize> {
+    pub from: usize,
+    pub to: usize,
+    pub copies: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub card: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "copy count overflowed at card {}", self.card)
+    }
+}
+
+impl Error for Overflow {}
+
+pub trait Counter: Clone + Zero + One + CheckedAdd {}
+
+impl<T: Clone + Zero + One + CheckedAdd> Counter for T {}
+
+fn run_simulation<T: Counter>(
+    cards: &[Card],
+    mut trace: Option<&mut Vec<Spawn<T>>>,
+) -> Result<Vec<T>, Overflow> {
+    let mut copies = vec![T::one(); cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let amount = copies[i].clone();
+        let last = (i + card.matching()).min(cards.len() - 1);
+        for j in i + 1..=last {
+            copies[j] = copies[j]
+                .checked_add(&amount)
+                .ok_or(Overflow { card: cards[j].id })?;
+            if let Some(trace) = trace.as_mut() {
+                trace.push(Spawn {
+                    from: card.id,
+                    to: cards[j].id,
+                    copies: amount.clone(),
+                });
+            }
+        }
+    }
+    Ok(copies)
+}
+
+pub fn simulate_checked<T: Counter>(cards: &[Card]) -> Result<Vec<T>, Overflow> {
+    run_simulation(cards, None)
+}
+
+pub fn simulate<T: Counter>(cards: &[Card]) -> Vec<T> {
+    simulate_checked(cards).unwrap_or_else(|overflow| panic!("{overflow}"))
+}
+
+pub fn simulate_with_trace<T: Counter>(cards: &[Card]) -> (Vec<T>, Vec<Spawn<T>>) {
+    let mut trace = vec![];
+    let copies =
+        run_simulation(cards, Some(&mut trace)).unwrap_or_else(|overflow| panic!("{overflow}"));
+    (copies, trace)
+}
+
+pub fn total_copies_checked<T: Counter>(cards: &[Card]) -> Result<T, Overflow> {
+    let copies = simulate_checked::<T>(cards)?;
+    let mut total = T::zero();
+    for (card, count) in cards.iter().zip(copies) {
+        total = total
+            .checked_add(&count)
+            .ok_or(Overflow { card: card.id })?;
+    }
+    Ok(total)
+}
+
+pub fn calculate_matching(card: &str) -> usize {
+    Card::parse(card).matching()
+}
+
+pub fn calculate_copies(cards: &str) -> usize {
+    simulate::<usize>(&parse_cards(cards)).iter().sum()
+}
+
+pub fn part_one(content: &str) -> usize {
+    total_score(&parse_cards(content), doubling)
+}
+
+pub fn part_two(content: &str) -> usize {
+    calculate_copies(content)
+}
+
+pub const SAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("13"),
+    part_two: Some("30"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+
+    use crate::download_day;
+
+    use super::{
+        calculate_copies, calculate_matching, doubling, linear, parse_cards, simulate,
+        simulate_checked, simulate_with_trace, total_copies_checked, total_score, Card, Overflow,
+        Spawn, SAMPLE,
+    };
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 4);
+        let res = super::part_one(&content);
+        println!("Part 1: {res}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 4);
+        let res = super::part_two(&content);
+        println!("Part two: {res}");
+    }
+
+    #[test]
+    fn score_test() {
+        let card = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let res = calculate_matching(card);
+        assert_eq!(doubling(res), 8);
+        assert_eq!(Card::parse(card).matching(), res);
+    }
+
+    #[test]
+    fn copies_test() {
+        let content = SAMPLE;
+        let res = calculate_copies(content);
+        assert_eq!(res, 30);
+    }
+
+    #[test]
+    fn calculate_pile() {
+        let content = SAMPLE;
+
+        let cards = parse_cards(content);
+        assert_eq!(total_score(&cards, doubling), 13);
+        assert_eq!(total_score(&cards, linear), 9);
+        assert_eq!(total_score(&cards, |matching| matching * matching), 25);
+    }
+
+    #[test]
+    fn card_parse() {
+        let card = Card::parse("Card  12:  1 21 | 69  1");
+        assert_eq!(card.id, 12);
+        assert_eq!(card.winning.into_iter().collect::<Vec<_>>(), [1, 21]);
+        assert_eq!(card.picked, [69, 1]);
+    }
+
+    #[test]
+    fn copies_per_card() {
+        let cards = parse_cards(SAMPLE);
+        assert_eq!(simulate::<usize>(&cards), [1, 2, 4, 8, 14, 1]);
+
+        let (_, trace) = simulate_with_trace::<usize>(&cards);
+        assert_eq!(
+            &trace[..4],
+            [(1, 2, 1), (1, 3, 1), (1, 4, 1), (1, 5, 1)].map(|(from, to, copies)| Spawn {
+                from,
+                to,
+                copies
+            })
+        );
+        assert_eq!(trace.len(), 9);
+        assert_eq!(
+            trace.last(),
+            Some(&Spawn {
+                from: 4,
+                to: 5,
+                copies: 8
+            })
+        );
+    }
+
+    #[test]
+    fn copies_clamp_at_last_card() {
+        let content = "Card 1: 1 2 3 | 1 2 3
+Card 2: 4 5 | 4 5";
+        let cards = parse_cards(content);
+        assert_eq!(simulate::<usize>(&cards), [1, 2]);
+        assert_eq!(calculate_copies(content), 3);
+    }
+
+    #[test]
+    fn large_piles() {
+        let content: String = (1..=200)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10\n"))
+            .collect();
+        let cards = parse_cards(&content);
+
+        let overflow = simulate_checked::<u64>(&cards).unwrap_err();
+        assert_eq!(overflow, Overflow { card: 66 });
+        assert_eq!(overflow.to_string(), "copy count overflowed at card 66");
+        assert_eq!(
+            total_copies_checked::<u128>(&cards),
+            Err(Overflow { card: 130 })
+        );
+
+        let big = simulate::<BigUint>(&cards);
+        let small = simulate_checked::<u128>(&cards[..100]).unwrap();
+        assert!(big[..100]
+            .iter()
+            .zip(&small)
+            .all(|(big, small)| *big == BigUint::from(*small)));
+        let total = total_copies_checked::<BigUint>(&cards).unwrap();
+        assert_eq!(total, big.iter().sum::<BigUint>());
+    }
+}
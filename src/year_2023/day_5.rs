@@ -0,0 +1,1084 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use crate::{
+    interval::{Bound, Interval, IntervalSet},
+    registry::Sample,
+    repl::Explore,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Map<T = usize> {
+    pub source: T,
+    pub destination: T,
+    pub range: T,
+}
+
+impl<T: Bound> Map<T> {
+    pub fn parse(line: &str) -> Result<Map<T>, AlmanacError<T>>
+    where
+        T: FromStr,
+    {
+        let numbers: Vec<T> = line
+            .split_whitespace()
+            .map(|v| v.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| AlmanacError::BadMap(line.to_string()))?;
+        match numbers[..] {
+            [destination, source, range] => {
+                let map = Map {
+                    source,
+                    destination,
+                    range,
+                };
+                map.source_end()?;
+                map.destination_end()?;
+                Ok(map)
+            }
+            _ => Err(AlmanacError::BadMap(line.to_string())),
+        }
+    }
+
+    pub fn source_end(&self) -> Result<T, AlmanacError<T>> {
+        self.source
+            .checked_add(self.range)
+            .ok_or(AlmanacError::Overflow(*self))
+    }
+
+    pub fn destination_end(&self) -> Result<T, AlmanacError<T>> {
+        self.destination
+            .checked_add(self.range)
+            .ok_or(AlmanacError::Overflow(*self))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlmanacError<T = usize> {
+    MissingSeeds,
+    BadSeed(String),
+    UnpairedSeeds(usize),
+    BadHeader(String),
+    BadMap(String),
+    BrokenChain { expected: String, found: String },
+    UnknownConversion { from: String, to: String },
+    Overlap(Map<T>, Map<T>),
+    Overflow(Map<T>),
+    RangeOverflow(LocationRange<T>),
+    TooWide(Interval<T>),
+}
+
+impl<T: Debug> Display for AlmanacError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlmanacError::MissingSeeds => write!(f, "expected a seeds line"),
+            AlmanacError::BadSeed(seed) => write!(f, "expected a seed number found {seed:?}"),
+            AlmanacError::UnpairedSeeds(count) => {
+                write!(f, "expected seed ranges in pairs found {count} numbers")
+            }
+            AlmanacError::BadHeader(line) => {
+                write!(f, "expected an `X-to-Y map:` header found {line:?}")
+            }
+            AlmanacError::BadMap(line) => {
+                write!(f, "expected `destination source range` found {line:?}")
+            }
+            AlmanacError::BrokenChain { expected, found } => {
+                write!(f, "expected a stage from {expected} found one from {found}")
+            }
+            AlmanacError::UnknownConversion { from, to } => {
+                write!(f, "no chain of stages converts {from} to {to}")
+            }
+            AlmanacError::Overlap(first, second) => {
+                write!(f, "maps {first:?} and {second:?} overlap")
+            }
+            AlmanacError::Overflow(map) => write!(f, "map {map:?} overflows"),
+            AlmanacError::RangeOverflow(range) => write!(f, "range {range:?} overflows"),
+            AlmanacError::TooWide(interval) => {
+                write!(f, "interval {interval:?} is too wide for a range")
+            }
+        }
+    }
+}
+
+impl<T: Debug> Error for AlmanacError<T> {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    pub from: String,
+    pub to: String,
+    pub maps: Vec<Map>,
+}
+
+impl Stage {
+    pub fn map_value(&self, n: usize) -> Result<usize, AlmanacError> {
+        for map in &self.maps {
+            if let Some(location) = is_in_range(*map, n)? {
+                return Ok(location);
+            }
+        }
+        Ok(n)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub stages: Vec<Stage>,
+}
+
+fn sections(content: &str) -> Vec<Vec<&str>> {
+    let mut sections = vec![];
+    let mut current = vec![];
+    for line in content.lines().map(str::trim) {
+        if !line.is_empty() {
+            current.push(line);
+        } else if !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+impl Almanac {
+    pub fn parse(content: &str) -> Result<Almanac, AlmanacError> {
+        let mut sections = sections(content).into_iter();
+        let seeds = sections
+            .next()
+            .and_then(|lines| lines.join(" ").strip_prefix("seeds:").map(str::to_string))
+            .ok_or(AlmanacError::MissingSeeds)?
+            .split_whitespace()
+            .map(|v| v.parse().map_err(|_| AlmanacError::BadSeed(v.to_string())))
+            .collect::<Result<_, _>>()?;
+
+        let mut stages: Vec<Stage> = vec![];
+        for section in sections {
+            let mut lines = section.into_iter();
+            let header = lines.next().unwrap_or_default();
+            let (from, to) = header
+                .strip_suffix(" map:")
+                .and_then(|name| name.split_once("-to-"))
+                .ok_or(AlmanacError::BadHeader(header.to_string()))?;
+            if let Some(previous) = stages.last() {
+                if previous.to != from {
+                    return Err(AlmanacError::BrokenChain {
+                        expected: previous.to.clone(),
+                        found: from.to_string(),
+                    });
+                }
+            }
+            stages.push(Stage {
+                from: from.to_string(),
+                to: to.to_string(),
+                maps: lines.map(Map::parse).collect::<Result<_, _>>()?,
+            });
+        }
+        Ok(Almanac { seeds, stages })
+    }
+
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.stages
+            .first()
+            .map(|stage| stage.from.as_str())
+            .into_iter()
+            .chain(self.stages.iter().map(|stage| stage.to.as_str()))
+    }
+
+    pub fn stages_between(&self, from: &str, to: &str) -> Result<&[Stage], AlmanacError> {
+        let unknown = || AlmanacError::UnknownConversion {
+            from: from.to_string(),
+            to: to.to_string(),
+        };
+        let start = self
+            .categories()
+            .position(|c| c == from)
+            .ok_or_else(unknown)?;
+        let end = self
+            .categories()
+            .position(|c| c == to)
+            .ok_or_else(unknown)?;
+        if end < start {
+            return Err(unknown());
+        }
+        Ok(&self.stages[start..end])
+    }
+
+    pub fn map_value(&self, from: &str, to: &str, n: usize) -> Result<usize, AlmanacError> {
+        self.stages_between(from, to)?
+            .iter()
+            .try_fold(n, |n, stage| stage.map_value(n))
+    }
+
+    pub fn compose(&self, from: &str, to: &str) -> Result<RangeMap, AlmanacError> {
+        let mut composed = RangeMap::default();
+        for stage in self.stages_between(from, to)? {
+            composed = composed.compose(&RangeMap::new(stage.maps.iter().copied())?);
+        }
+        Ok(composed)
+    }
+
+    pub fn seed_to_location(&self) -> RangeMap {
+        self.compose("seed", "location").unwrap()
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct LocationRange<T = usize> {
+    location: T,
+    range: T,
+}
+
+impl<T: Bound> LocationRange<T> {
+    pub fn new(location: T, range: T) -> Self {
+        LocationRange { location, range }
+    }
+
+    pub fn end(&self) -> Result<T, AlmanacError<T>> {
+        self.location
+            .checked_add(self.range)
+            .ok_or(AlmanacError::RangeOverflow(*self))
+    }
+
+    pub fn interval(&self) -> Option<Interval<T>> {
+        Interval::with_len(self.location, self.range)
+    }
+}
+
+impl<T: Bound> TryFrom<Interval<T>> for LocationRange<T> {
+    type Error = AlmanacError<T>;
+
+    fn try_from(interval: Interval<T>) -> Result<Self, Self::Error> {
+        Ok(LocationRange {
+            location: interval.start(),
+            range: interval.len().ok_or(AlmanacError::TooWide(interval))?,
+        })
+    }
+}
+
+pub fn normalise<T: Bound>(
+    ranges: impl IntoIterator<Item = LocationRange<T>>,
+) -> Result<BTreeSet<LocationRange<T>>, AlmanacError<T>> {
+    let mut intervals = vec![];
+    for range in ranges {
+        if range.range > T::ZERO {
+            intervals.push(range.interval().ok_or(AlmanacError::RangeOverflow(range))?);
+        }
+    }
+    IntervalSet::normalise(intervals)
+        .iter()
+        .map(|interval| LocationRange::try_from(*interval))
+        .collect()
+}
+
+pub fn is_in_range<T: Bound>(map: Map<T>, initial: T) -> Result<Option<T>, AlmanacError<T>> {
+    if initial >= map.source && initial < map.source_end()? {
+        let location = map
+            .destination
+            .checked_add(initial - map.source)
+            .ok_or(AlmanacError::Overflow(map))?;
+        return Ok(Some(location));
+    }
+    Ok(None)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T = usize> {
+    maps: Vec<Map<T>>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        RangeMap { maps: vec![] }
+    }
+}
+
+impl<T: Bound> RangeMap<T> {
+    pub fn new(maps: impl IntoIterator<Item = Map<T>>) -> Result<RangeMap<T>, AlmanacError<T>> {
+        let mut maps: Vec<Map<T>> = maps.into_iter().filter(|map| map.range > T::ZERO).collect();
+        maps.sort_by_key(|map| map.source);
+        for map in &maps {
+            map.source_end()?;
+            map.destination_end()?;
+        }
+        for pair in maps.windows(2) {
+            if pair[0].source_end()? > pair[1].source {
+                return Err(AlmanacError::Overlap(pair[0], pair[1]));
+            }
+        }
+        Ok(RangeMap { maps })
+    }
+
+    pub fn maps(&self) -> &[Map<T>] {
+        &self.maps
+    }
+
+    pub fn lookup(&self, n: T) -> T {
+        let idx = self.maps.partition_point(|map| map.source + map.range <= n);
+        match self.maps.get(idx) {
+            Some(map) if map.source <= n => map.destination + (n - map.source),
+            _ => n,
+        }
+    }
+
+    pub fn segments(&self, interval: LocationRange<T>) -> Result<Vec<Map<T>>, AlmanacError<T>> {
+        Ok(self.segments_between(interval.location, interval.end()?))
+    }
+
+    fn segments_between(&self, mut start: T, end: T) -> Vec<Map<T>> {
+        let mut res = vec![];
+        let first = self
+            .maps
+            .partition_point(|map| map.source + map.range <= start);
+        for map in &self.maps[first..] {
+            if start >= end || map.source >= end {
+                break;
+            }
+            if start < map.source {
+                push_identity(&mut res, start, map.source);
+                start = map.source;
+            }
+            let covered_end = end.min(map.source + map.range);
+            res.push(Map {
+                source: start,
+                destination: map.destination + (start - map.source),
+                range: covered_end - start,
+            });
+            start = covered_end;
+        }
+        if start < end {
+            push_identity(&mut res, start, end);
+        }
+        res
+    }
+
+    pub fn apply(
+        &self,
+        interval: LocationRange<T>,
+    ) -> Result<Vec<LocationRange<T>>, AlmanacError<T>> {
+        Ok(self
+            .segments(interval)?
+            .into_iter()
+            .map(|segment| LocationRange {
+                location: segment.destination,
+                range: segment.range,
+            })
+            .collect())
+    }
+
+    pub fn breakpoints(&self) -> Vec<Map<T>> {
+        self.segments_between(T::MIN, T::MAX)
+    }
+
+    pub fn compose(&self, next: &RangeMap<T>) -> RangeMap<T> {
+        let mut maps = vec![];
+        for outer in self.breakpoints() {
+            let image = LocationRange {
+                location: outer.destination,
+                range: outer.range,
+            };
+            let inner_segments = next
+                .segments(image)
+                .expect("segments lie within validated maps");
+            for inner in inner_segments {
+                maps.push(Map {
+                    source: outer.source + (inner.source - outer.destination),
+                    destination: inner.destination,
+                    range: inner.range,
+                });
+            }
+        }
+        maps.retain(|map| map.source != map.destination);
+        RangeMap { maps }
+    }
+
+    pub fn preimage(&self, n: T) -> Vec<T> {
+        self.breakpoints()
+            .into_iter()
+            .filter_map(|map| {
+                let offset = n.checked_sub(map.destination)?;
+                (n >= map.destination && offset < map.range).then(|| map.source + offset)
+            })
+            .collect()
+    }
+
+    pub fn inverse(&self) -> Option<RangeMap<T>> {
+        let maps = self.breakpoints().into_iter().map(|map| Map {
+            source: map.destination,
+            destination: map.source,
+            range: map.range,
+        });
+        let mut inverse = RangeMap::new(maps).ok()?;
+        inverse.maps.retain(|map| map.source != map.destination);
+        Some(inverse)
+    }
+}
+
+fn push_identity<T: Bound>(res: &mut Vec<Map<T>>, mut start: T, end: T) {
+    while start < end {
+        let range = end.checked_sub(start).unwrap_or(T::MAX);
+        res.push(Map {
+            source: start,
+            destination: start,
+            range,
+        });
+        start = start + range;
+    }
+}
+
+pub fn calculate_location_ranges<T: Bound>(
+    initial: BTreeSet<LocationRange<T>>,
+    map_collection: &[Map<T>],
+) -> Result<BTreeSet<LocationRange<T>>, AlmanacError<T>> {
+    let range_map = RangeMap::new(map_collection.iter().copied())?;
+    let mut res = vec![];
+    for range in initial {
+        res.extend(range_map.apply(range)?);
+    }
+    normalise(res)
+}
+
+pub fn calculate_location<T: Bound>(
+    initial: BTreeSet<T>,
+    map_collection: &[Map<T>],
+) -> Result<BTreeSet<T>, AlmanacError<T>> {
+    let mut source_to_min: BTreeMap<T, T> = BTreeMap::new();
+    for source in initial.iter() {
+        for map in map_collection.iter().copied() {
+            if let Some(location) = is_in_range(map, *source)? {
+                source_to_min
+                    .entry(*source)
+                    .and_modify(|e| *e = (*e).min(location))
+                    .or_insert(location);
+            }
+        }
+    }
+
+    for source in initial {
+        source_to_min.entry(source).or_insert(source);
+    }
+
+    Ok(source_to_min.into_values().collect())
+}
+
+pub fn seed_ranges(almanac: &Almanac) -> Result<BTreeSet<LocationRange>, AlmanacError> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(AlmanacError::UnpairedSeeds(almanac.seeds.len()));
+    }
+    Ok(almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|x| LocationRange {
+            location: x[0],
+            range: x[1],
+        })
+        .collect())
+}
+
+pub fn part_one(content: &str) -> usize {
+    let almanac = Almanac::parse(content).unwrap();
+    let mut initial: BTreeSet<usize> = almanac.seeds.iter().copied().collect();
+    for stage in &almanac.stages {
+        initial = calculate_location(initial, &stage.maps).unwrap();
+    }
+    *initial.iter().min().unwrap()
+}
+
+pub fn lowest_location<T: Bound>(
+    composed: &RangeMap<T>,
+    seeds: &BTreeSet<LocationRange<T>>,
+) -> Result<Option<T>, AlmanacError<T>> {
+    let mut lowest = None;
+    for range in seeds {
+        for piece in composed.apply(*range)? {
+            lowest = Some(lowest.map_or(piece.location, |lowest: T| lowest.min(piece.location)));
+        }
+    }
+    Ok(lowest)
+}
+
+pub fn part_two(content: &str) -> usize {
+    let almanac = Almanac::parse(content).unwrap();
+    lowest_location(&almanac.seed_to_location(), &seed_ranges(&almanac).unwrap())
+        .unwrap()
+        .unwrap()
+}
+
+pub fn render_breakpoints(composed: &RangeMap) -> String {
+    composed
+        .breakpoints()
+        .iter()
+        .map(|map| {
+            let end = match map.source.checked_add(map.range) {
+                Some(end) if end < usize::MAX => end.to_string(),
+                _ => "..".to_string(),
+            };
+            format!(
+                "{:>12} {:>12} -> {:>12} ({:+})",
+                map.source,
+                end,
+                map.destination,
+                map.destination as i128 - map.source as i128
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct SeedMapping {
+    almanac: Almanac,
+    composed: RangeMap,
+}
+
+impl Explore for SeedMapping {
+    fn show(&self) -> String {
+        render_breakpoints(&self.composed)
+    }
+
+    fn query(&self, command: &str, args: &[&str]) -> Option<String> {
+        match (command, args) {
+            ("location", [seed]) => {
+                let seed: usize = seed.parse().ok()?;
+                Some(self.composed.lookup(seed).to_string())
+            }
+            ("seed", [location]) => {
+                let location: usize = location.parse().ok()?;
+                let seeds: Vec<String> = self
+                    .composed
+                    .preimage(location)
+                    .iter()
+                    .map(|seed| seed.to_string())
+                    .collect();
+                Some(seeds.join(", "))
+            }
+            ("map", [from, to, n]) => {
+                let n: usize = n.parse().ok()?;
+                Some(match self.almanac.map_value(from, to, n) {
+                    Ok(value) => value.to_string(),
+                    Err(err) => err.to_string(),
+                })
+            }
+            ("breakpoints", [from, to]) => Some(match self.almanac.compose(from, to) {
+                Ok(composed) => render_breakpoints(&composed),
+                Err(err) => err.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn commands(&self) -> &'static [&'static str] {
+        &[
+            "location <seed>",
+            "seed <location>",
+            "map <from> <to> <n>",
+            "breakpoints <from> <to>",
+        ]
+    }
+}
+
+pub fn explore(content: &str) -> Box<dyn Explore + '_> {
+    let almanac = Almanac::parse(content).unwrap();
+    let composed = almanac.seed_to_location();
+    Box::new(SeedMapping { almanac, composed })
+}
+
+pub const SAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("35"),
+    part_two: Some("46"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::SAMPLE;
+    use std::collections::BTreeSet;
+
+    use crate::{
+        download_day,
+        interval::Interval,
+        snapshot::Snapshot,
+        timeit,
+        year_2023::day_5::{
+            calculate_location, calculate_location_ranges, is_in_range, lowest_location, normalise,
+            render_breakpoints, seed_ranges, Almanac, AlmanacError, LocationRange, Map, RangeMap,
+        },
+    };
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 5);
+        let res = super::part_two(&content);
+
+        println!("Part 2: {res}")
+    }
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 5);
+        let res = super::part_one(&content);
+        println!("Part 1: {res}")
+    }
+
+    #[test]
+    fn calculate_location_test() {
+        let initial: BTreeSet<usize> = BTreeSet::from([79, 14, 55, 13]);
+        let maps = [
+            Map::parse("50 98 2").unwrap(),
+            Map::parse("52 50 48").unwrap(),
+        ];
+        let res = calculate_location(initial, &maps);
+        assert_eq!(res, Ok(BTreeSet::from([81, 14, 57, 13])));
+    }
+
+    #[test]
+    fn part_one_sample() {
+        let almanac = Almanac::parse(SAMPLE).unwrap();
+        let mut initial: BTreeSet<usize> = almanac.seeds.iter().copied().collect();
+        for stage in &almanac.stages {
+            initial = calculate_location(initial, &stage.maps).unwrap();
+        }
+        assert_eq!(initial, BTreeSet::from([82, 43, 86, 35]));
+    }
+
+    #[test]
+    fn part_two_sample() {
+        let almanac = Almanac::parse(SAMPLE).unwrap();
+        let mut initial = seed_ranges(&almanac).unwrap();
+        for stage in &almanac.stages {
+            initial = calculate_location_ranges(initial, &stage.maps).unwrap();
+        }
+        assert_eq!(
+            initial
+                .iter()
+                .fold(usize::MAX, |acc, x| acc.min(x.location)),
+            46
+        );
+    }
+
+    #[test]
+    fn almanac_stages() {
+        let almanac = Almanac::parse(SAMPLE).unwrap();
+        assert_eq!(almanac.seeds, [79, 14, 55, 13]);
+        assert_eq!(
+            almanac.categories().collect::<Vec<_>>(),
+            [
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+        assert_eq!(almanac.stages[0].maps.len(), 2);
+        assert_eq!(almanac.map_value("seed", "soil", 79), Ok(81));
+        assert_eq!(almanac.map_value("seed", "location", 79), Ok(82));
+        assert_eq!(almanac.map_value("soil", "water", 81), Ok(81));
+        assert_eq!(almanac.map_value("light", "light", 5), Ok(5));
+        assert_eq!(
+            almanac.map_value("location", "seed", 82),
+            Err(AlmanacError::UnknownConversion {
+                from: "location".to_string(),
+                to: "seed".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn almanac_validation() {
+        let broken = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n1 2 3";
+        assert_eq!(
+            Almanac::parse(broken),
+            Err(AlmanacError::BrokenChain {
+                expected: "soil".to_string(),
+                found: "water".to_string()
+            })
+        );
+        assert_eq!(
+            Almanac::parse("seeds: 1 2\n\nseed-to-soil map:\n1 2"),
+            Err(AlmanacError::BadMap("1 2".to_string()))
+        );
+        assert_eq!(
+            Almanac::parse("seeds: 1 2\n\nseed to soil:\n1 2 3"),
+            Err(AlmanacError::BadHeader("seed to soil:".to_string()))
+        );
+        assert_eq!(Almanac::parse("1 2"), Err(AlmanacError::MissingSeeds));
+
+        let odd = Almanac::parse("seeds: 1 2 3\n\nseed-to-soil map:\n1 2 3").unwrap();
+        assert_eq!(seed_ranges(&odd), Err(AlmanacError::UnpairedSeeds(3)));
+    }
+
+    #[test]
+    fn almanac_line_endings() {
+        let expected = Almanac::parse(SAMPLE).unwrap();
+        let crlf = SAMPLE.replace('\n', "\r\n");
+        assert_eq!(Almanac::parse(&crlf).unwrap(), expected);
+        let padded = SAMPLE.replace("\n\n", "\n  \n\t\n");
+        assert_eq!(Almanac::parse(&padded).unwrap(), expected);
+    }
+
+    #[test]
+    fn range_map_apply() {
+        let range_map = RangeMap::new([
+            Map {
+                source: 20,
+                destination: 100,
+                range: 10,
+            },
+            Map {
+                source: 10,
+                destination: 0,
+                range: 5,
+            },
+        ])
+        .unwrap();
+        let pieces = range_map
+            .apply(LocationRange {
+                location: 5,
+                range: 30,
+            })
+            .unwrap();
+        let pieces: Vec<_> = pieces.iter().map(|r| (r.location, r.range)).collect();
+        assert_eq!(pieces, [(5, 5), (0, 5), (15, 5), (100, 10), (30, 5)]);
+
+        let inside = range_map.apply(LocationRange {
+            location: 22,
+            range: 3,
+        });
+        assert_eq!(
+            inside,
+            Ok(vec![LocationRange {
+                location: 102,
+                range: 3
+            }])
+        );
+        assert!(range_map
+            .apply(LocationRange {
+                location: 7,
+                range: 0
+            })
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn range_map_lookup() {
+        let almanac = Almanac::parse(SAMPLE).unwrap();
+        for stage in &almanac.stages {
+            let range_map = RangeMap::new(stage.maps.iter().copied()).unwrap();
+            for n in 0..120 {
+                assert_eq!(Ok(range_map.lookup(n)), stage.map_value(n), "{n}");
+            }
+        }
+    }
+
+    #[test]
+    fn range_map_rejects_overlaps() {
+        let first = Map {
+            source: 0,
+            destination: 10,
+            range: 5,
+        };
+        let second = Map {
+            source: 4,
+            destination: 50,
+            range: 2,
+        };
+        assert_eq!(
+            RangeMap::new([second, first]),
+            Err(AlmanacError::Overlap(first, second))
+        );
+    }
+
+    #[test]
+    fn composed_mapping() {
+        let almanac = Almanac::parse(SAMPLE).unwrap();
+        let composed = almanac.seed_to_location();
+        for seed in 0..120 {
+            assert_eq!(
+                composed.lookup(seed),
+                almanac.map_value("seed", "location", seed).unwrap(),
+                "{seed}"
+            );
+        }
+        assert_eq!(composed.lookup(79), 82);
+        assert_eq!(composed.preimage(46), [82]);
+        assert_eq!(
+            lowest_location(&composed, &seed_ranges(&almanac).unwrap()),
+            Ok(Some(46))
+        );
+
+        let inverse = composed.inverse().unwrap();
+        for seed in 0..120 {
+            assert_eq!(inverse.lookup(composed.lookup(seed)), seed);
+        }
+    }
+
+    #[test]
+    fn non_injective_has_no_inverse() {
+        let range_map = RangeMap::new([Map {
+            source: 0,
+            destination: 10,
+            range: 5,
+        }])
+        .unwrap();
+        assert_eq!(range_map.preimage(12), [2, 12]);
+        assert_eq!(range_map.preimage(3), Vec::<usize>::new());
+        assert!(range_map.inverse().is_none());
+    }
+
+    #[test]
+    fn breakpoint_table() {
+        let range_map = RangeMap::new([Map {
+            source: 50,
+            destination: 52,
+            range: 48,
+        }])
+        .unwrap();
+        assert_eq!(
+            render_breakpoints(&range_map),
+            "           0           50 ->            0 (+0)
+          50           98 ->           52 (+2)
+          98           .. ->           98 (+0)"
+        );
+    }
+
+    #[test]
+    fn normalise_location_ranges() {
+        let ranges = [(10, 5), (12, 10), (22, 3), (40, 1), (50, 0)]
+            .map(|(location, range)| LocationRange { location, range });
+        assert_eq!(
+            normalise(ranges).unwrap(),
+            BTreeSet::from([
+                LocationRange {
+                    location: 10,
+                    range: 15
+                },
+                LocationRange {
+                    location: 40,
+                    range: 1
+                }
+            ])
+        );
+
+        let overflowing = LocationRange::new(u8::MAX, 2);
+        assert_eq!(
+            normalise([overflowing]),
+            Err(AlmanacError::RangeOverflow(overflowing))
+        );
+        let halves = [LocationRange::new(0u8, 128), LocationRange::new(128, 128)];
+        assert_eq!(
+            normalise(halves),
+            Err(AlmanacError::TooWide(Interval::closed(0, 255).unwrap()))
+        );
+        let top = LocationRange::new(u8::MAX, 1);
+        assert_eq!(normalise([top]), Ok(BTreeSet::from([top])));
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let near = u64::MAX - 10;
+        assert_eq!(
+            Map::<u64>::parse(&format!("0 {near} 11")),
+            Err(AlmanacError::Overflow(Map {
+                source: near,
+                destination: 0,
+                range: 11
+            }))
+        );
+        assert_eq!(
+            Map::<u64>::parse(&format!("{near} 0 20")),
+            Err(AlmanacError::Overflow(Map {
+                source: 0,
+                destination: near,
+                range: 20
+            }))
+        );
+        assert!(Map::<u64>::parse(&format!("0 {near} 10")).is_ok());
+        assert_eq!(
+            Map::<u64>::parse("1 2 18446744073709551616"),
+            Err(AlmanacError::BadMap("1 2 18446744073709551616".to_string()))
+        );
+
+        let unchecked = Map {
+            source: 250u8,
+            destination: 0,
+            range: 10,
+        };
+        assert_eq!(
+            is_in_range(unchecked, 252),
+            Err(AlmanacError::Overflow(unchecked))
+        );
+        assert_eq!(
+            RangeMap::new([unchecked]),
+            Err(AlmanacError::Overflow(unchecked))
+        );
+
+        let range = LocationRange::new(near, 11);
+        assert_eq!(range.end(), Err(AlmanacError::RangeOverflow(range)));
+        assert_eq!(
+            calculate_location_ranges(BTreeSet::from([range]), &[]),
+            Err(AlmanacError::RangeOverflow(range))
+        );
+    }
+
+    #[test]
+    fn boundary_values() {
+        let near = u64::MAX - 10;
+        let top = Map {
+            source: near,
+            destination: 0,
+            range: 10,
+        };
+        let bottom = Map {
+            source: 0,
+            destination: near,
+            range: 10,
+        };
+        assert_eq!(is_in_range(top, u64::MAX - 1), Ok(Some(9)));
+        assert_eq!(is_in_range(top, u64::MAX), Ok(None));
+        assert_eq!(is_in_range(bottom, 9), Ok(Some(u64::MAX - 1)));
+
+        let range_map = RangeMap::new([top, bottom]).unwrap();
+        assert_eq!(range_map.lookup(u64::MAX - 1), 9);
+        assert_eq!(range_map.lookup(u64::MAX), u64::MAX);
+        assert_eq!(range_map.lookup(0), near);
+        assert_eq!(range_map.inverse().unwrap().lookup(9), u64::MAX - 1);
+
+        let pieces = range_map
+            .apply(LocationRange::new(u64::MAX - 15, 15))
+            .unwrap();
+        assert_eq!(
+            pieces,
+            [
+                LocationRange::new(u64::MAX - 15, 5),
+                LocationRange::new(0, 10)
+            ]
+        );
+        assert_eq!(
+            calculate_location(BTreeSet::from([u64::MAX - 1, 3]), &[top, bottom]),
+            Ok(BTreeSet::from([9, near + 3]))
+        );
+    }
+
+    #[test]
+    fn signed_boundary_values() {
+        let negative = Map {
+            source: -10i64,
+            destination: 100,
+            range: 5,
+        };
+        let one_way = RangeMap::new([negative]).unwrap();
+        assert_eq!(one_way.preimage(102), [-8, 102]);
+        assert_eq!(one_way.inverse(), None);
+
+        let swap = Map {
+            source: 100,
+            destination: -10,
+            range: 5,
+        };
+        let range_map = RangeMap::new([negative, swap]).unwrap();
+        assert_eq!(range_map.lookup(-8), 102);
+        assert_eq!(range_map.inverse().unwrap().lookup(102), -8);
+        assert_eq!(range_map.preimage(102), [-8]);
+        assert_eq!(RangeMap::default().compose(&range_map).lookup(-8), 102);
+        assert_eq!(range_map.compose(&RangeMap::default()).lookup(-8), 102);
+
+        let breakpoints = range_map.breakpoints();
+        assert_eq!(breakpoints.first().unwrap().source, i64::MIN);
+        let last = breakpoints.last().unwrap();
+        assert_eq!(last.source + last.range, i64::MAX);
+        assert!(breakpoints
+            .windows(2)
+            .all(|pair| pair[0].source + pair[0].range == pair[1].source));
+
+        let shift = RangeMap::new([
+            Map {
+                source: i8::MIN,
+                destination: 0,
+                range: 10,
+            },
+            Map {
+                source: 0,
+                destination: i8::MIN,
+                range: 10,
+            },
+        ])
+        .unwrap();
+        let back = shift.inverse().unwrap();
+        assert_eq!(back.lookup(5), -123);
+        assert_eq!(back.compose(&shift).lookup(5), 5);
+        assert_eq!(shift.compose(&back).lookup(-123), -123);
+        assert!(shift.compose(&back).maps().is_empty());
+    }
+
+    #[test]
+    fn time_part_two() {
+        timeit(part_two)
+    }
+
+    #[test]
+    fn location_ranges_snapshot() {
+        let initial = BTreeSet::from([
+            LocationRange {
+                location: 79,
+                range: 14,
+            },
+            LocationRange {
+                location: 55,
+                range: 13,
+            },
+        ]);
+        let maps = [
+            Map::parse("50 98 2").unwrap(),
+            Map::parse("52 50 48").unwrap(),
+        ];
+        let res = calculate_location_ranges(initial, &maps).unwrap();
+        assert_eq!(
+            res.snapshot(),
+            "LocationRange { location: 57, range: 13 }
+LocationRange { location: 81, range: 14 }
+"
+        );
+    }
+}
@@ -0,0 +1,501 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{interval::Interval, registry::Sample};
+
+pub fn new_records(time: usize, current_record: usize) -> usize {
+    let mut count = 0;
+    for i in 0..=time {
+        let speed = i;
+        let distance = (time - i) * speed;
+        if distance > current_record {
+            count += 1;
+        } else if count > 0 {
+            break;
+        }
+    }
+    count
+}
+
+pub fn new_records_math(time: usize, current_record: usize) -> usize {
+    let a: i64 = -1;
+    let b: i64 = time as i64;
+    let c = -(current_record as i64);
+    let discriminant = ((b.pow(2) - 4 * a * c) as f64).sqrt();
+    if discriminant <= 0f64 {
+        return 0;
+    }
+    let root_one = (-b as f64 + discriminant) / 2f64 * a as f64;
+    let root_two = (-b as f64 - discriminant) / 2f64 * a as f64;
+    (root_two.ceil() - (root_one + 1f64).floor()) as usize
+}
+
+fn first_winning_hold(time: u128, record: u128) -> Option<u128> {
+    let beats = |hold: u128| hold * (time - hold) > record;
+    if !beats(time / 2) {
+        return None;
+    }
+    let root = (time * time - 4 * record).isqrt();
+    let mut first = (time - root) / 2;
+    while !beats(first) {
+        first += 1;
+    }
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+    Some(first)
+}
+
+pub fn new_records_exact(time: usize, current_record: usize) -> usize {
+    match first_winning_hold(time as u128, current_record as u128) {
+        Some(first) => (time as u128 - 2 * first + 1) as usize,
+        None => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceModel {
+    pub acceleration: usize,
+    pub max_speed: Option<usize>,
+    pub startup: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceOutcome {
+    pub wins: Option<Interval<usize>>,
+    pub best_hold: usize,
+    pub best_distance: u128,
+}
+
+impl Default for RaceModel {
+    fn default() -> Self {
+        RaceModel {
+            acceleration: 1,
+            max_speed: None,
+            startup: 0,
+        }
+    }
+}
+
+impl RaceModel {
+    pub fn speed(&self, hold: usize) -> u128 {
+        let speed = hold as u128 * self.acceleration as u128;
+        match self.max_speed {
+            Some(max) => speed.min(max as u128),
+            None => speed,
+        }
+    }
+
+    pub fn distance(&self, time: usize, hold: usize) -> u128 {
+        let moving = time.saturating_sub(hold).saturating_sub(self.startup);
+        self.speed(hold) * moving as u128
+    }
+
+    fn moving_time(&self, time: usize) -> usize {
+        time.saturating_sub(self.startup)
+    }
+
+    fn cap_hold(&self) -> Option<usize> {
+        let max = self.max_speed?;
+        if self.acceleration == 0 {
+            return None;
+        }
+        Some(max.div_ceil(self.acceleration))
+    }
+
+    pub fn best_hold(&self, time: usize) -> (usize, u128) {
+        let moving = self.moving_time(time);
+        let mut candidates = vec![0, moving / 2, moving.div_ceil(2)];
+        if let Some(cap) = self.cap_hold() {
+            candidates.extend([cap.saturating_sub(1), cap]);
+        }
+        candidates
+            .into_iter()
+            .filter(|&hold| hold <= moving)
+            .map(|hold| (hold, self.distance(time, hold)))
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+            .unwrap()
+    }
+
+    pub fn race(&self, time: usize, record: usize) -> RaceOutcome {
+        let (best_hold, best_distance) = self.best_hold(time);
+        let wins = if best_distance <= record as u128 {
+            None
+        } else if self.max_speed.is_none() {
+            self.solve(time, record)
+        } else {
+            self.search(time, record, best_hold)
+        };
+        RaceOutcome {
+            wins,
+            best_hold,
+            best_distance,
+        }
+    }
+
+    fn solve(&self, time: usize, record: usize) -> Option<Interval<usize>> {
+        let moving = self.moving_time(time);
+        let threshold = record / self.acceleration;
+        let first = first_winning_hold(moving as u128, threshold as u128)? as usize;
+        Interval::closed(first, moving - first)
+    }
+
+    fn search(&self, time: usize, record: usize, best: usize) -> Option<Interval<usize>> {
+        let beats = |hold: usize| self.distance(time, hold) > record as u128;
+        let (mut lo, mut hi) = (0, best);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if beats(mid) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        let first = lo;
+        let (mut lo, mut hi) = (best, self.moving_time(time));
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if beats(mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        Interval::closed(first, lo)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kerning {
+    Separate,
+    Concatenated,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SheetError {
+    MissingRow(&'static str),
+    BadNumber(String),
+    ColumnMismatch { times: usize, distances: usize },
+}
+
+impl Display for SheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SheetError::MissingRow(label) => write!(f, "missing {label} row"),
+            SheetError::BadNumber(n) => write!(f, "invalid number {n:?}"),
+            SheetError::ColumnMismatch { times, distances } => {
+                write!(f, "{times} times but {distances} distances")
+            }
+        }
+    }
+}
+
+impl Error for SheetError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: usize,
+    pub record: usize,
+}
+
+impl Race {
+    pub fn new_records(&self) -> usize {
+        new_records_exact(self.time, self.record)
+    }
+
+    pub fn run(&self, model: &RaceModel) -> RaceOutcome {
+        model.race(self.time, self.record)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+}
+
+impl RaceSheet {
+    pub fn parse(content: &str, kerning: Kerning) -> Result<Self, SheetError> {
+        let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+        let times = split_row(lines.next(), "Time")?;
+        let distances = split_row(lines.next(), "Distance")?;
+        if times.len() != distances.len() {
+            return Err(SheetError::ColumnMismatch {
+                times: times.len(),
+                distances: distances.len(),
+            });
+        }
+        let times = parse_columns(times, kerning)?;
+        let distances = parse_columns(distances, kerning)?;
+        let races = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, record)| Race { time, record })
+            .collect();
+        Ok(RaceSheet { races })
+    }
+
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    pub fn margin(&self) -> usize {
+        self.races.iter().map(Race::new_records).product()
+    }
+}
+
+fn split_row<'a>(line: Option<&'a str>, label: &'static str) -> Result<Vec<&'a str>, SheetError> {
+    line.and_then(|line| line.strip_prefix(label))
+        .and_then(|line| line.strip_prefix(':'))
+        .map(|values| values.split_whitespace().collect())
+        .ok_or(SheetError::MissingRow(label))
+}
+
+fn parse_columns(columns: Vec<&str>, kerning: Kerning) -> Result<Vec<usize>, SheetError> {
+    let columns = match kerning {
+        Kerning::Separate => columns.into_iter().map(String::from).collect(),
+        Kerning::Concatenated if columns.is_empty() => vec![],
+        Kerning::Concatenated => vec![columns.concat()],
+    };
+    columns
+        .into_iter()
+        .map(|n| n.parse().map_err(|_| SheetError::BadNumber(n)))
+        .collect()
+}
+
+pub fn part_one(content: &str) -> usize {
+    RaceSheet::parse(content, Kerning::Separate)
+        .unwrap()
+        .margin()
+}
+
+pub fn part_two(content: &str) -> usize {
+    RaceSheet::parse(content, Kerning::Concatenated)
+        .unwrap()
+        .margin()
+}
+
+pub const SAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("288"),
+    part_two: Some("71503"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::SAMPLE;
+    use crate::{
+        download_day, timeit,
+        year_2023::day_6::{
+            new_records, new_records_exact, new_records_math, Kerning, Race, RaceModel, RaceSheet,
+            SheetError,
+        },
+        TestRng,
+    };
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 6);
+        let res = super::part_one(&content);
+        println!("Part 1: {res}")
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 6);
+        let res = super::part_two(&content);
+        println!("Part 2: {}", res);
+    }
+
+    #[test]
+    fn part_one_sample() {
+        let sheet = RaceSheet::parse(SAMPLE, Kerning::Separate).unwrap();
+        let res: usize = sheet
+            .races()
+            .iter()
+            .map(|race| new_records(race.time, race.record))
+            .product();
+        assert_eq!(res, 288)
+    }
+
+    #[test]
+    fn part_two_sample() {
+        let sheet = RaceSheet::parse(SAMPLE, Kerning::Concatenated).unwrap();
+        let race = sheet.races()[0];
+        let res = new_records_math(race.time, race.record);
+        assert_eq!(res, 71503)
+    }
+
+    #[test]
+    fn race_sheet_kerning() {
+        let separate = RaceSheet::parse(SAMPLE, Kerning::Separate).unwrap();
+        assert_eq!(
+            separate.races(),
+            [
+                Race { time: 7, record: 9 },
+                Race {
+                    time: 15,
+                    record: 40
+                },
+                Race {
+                    time: 30,
+                    record: 200
+                }
+            ]
+        );
+        let concatenated = RaceSheet::parse(SAMPLE, Kerning::Concatenated).unwrap();
+        assert_eq!(
+            concatenated.races(),
+            [Race {
+                time: 71530,
+                record: 940200
+            }]
+        );
+        assert_eq!(concatenated.margin(), 71503);
+        let outcome = concatenated.races()[0].run(&RaceModel::default());
+        assert_eq!(outcome.wins.unwrap().len(), Some(71503));
+    }
+
+    #[test]
+    fn race_sheet_errors() {
+        assert_eq!(
+            RaceSheet::parse("Time: 7 15\nDistance: 9", Kerning::Separate),
+            Err(SheetError::ColumnMismatch {
+                times: 2,
+                distances: 1
+            })
+        );
+        assert_eq!(
+            RaceSheet::parse("Time: 7 15\nDistance: 9", Kerning::Concatenated),
+            Err(SheetError::ColumnMismatch {
+                times: 2,
+                distances: 1
+            })
+        );
+        assert_eq!(
+            RaceSheet::parse("Time: 7", Kerning::Separate),
+            Err(SheetError::MissingRow("Distance"))
+        );
+        assert_eq!(
+            RaceSheet::parse("Distance: 9\nTime: 7", Kerning::Separate),
+            Err(SheetError::MissingRow("Time"))
+        );
+        assert_eq!(
+            RaceSheet::parse("Time: 7x\nDistance: 9", Kerning::Separate),
+            Err(SheetError::BadNumber("7x".to_string()))
+        );
+        let overflow = format!("Time: {} 0\nDistance: 1 1", usize::MAX);
+        assert!(matches!(
+            RaceSheet::parse(&overflow, Kerning::Concatenated),
+            Err(SheetError::BadNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_math() {
+        println!("{}", new_records_math(7, 9));
+    }
+
+    #[test]
+    fn exact_matches_brute_force() {
+        let mut rng = TestRng::new(6);
+        for _ in 0..2000 {
+            let time = rng.below(200);
+            let record = rng.below(time * time / 4 + 2);
+            assert_eq!(
+                new_records_exact(time, record),
+                new_records(time, record),
+                "time {time}, record {record}"
+            );
+        }
+    }
+
+    #[test]
+    fn exact_ties_do_not_win() {
+        assert_eq!(new_records_exact(30, 200), 9);
+        assert_eq!(new_records_exact(10, 25), 0);
+        assert_eq!(new_records_exact(10, 24), 1);
+        assert_eq!(new_records_exact(10, 21), 3);
+        assert_eq!(new_records_exact(0, 0), 0);
+        assert_eq!(new_records_exact(1, 0), 0);
+        assert_eq!(new_records_exact(2, 0), 1);
+    }
+
+    #[test]
+    fn exact_large_values() {
+        let time = 1 << 31;
+        let record = (time / 2) * (time / 2) - 1;
+        assert_eq!(new_records_exact(time, record), 1);
+        assert_eq!(new_records_exact(time, record - 2), 3);
+        assert_eq!(new_records_exact(time, 0), time - 1);
+        assert_eq!(new_records_exact(usize::MAX, 0), usize::MAX - 1);
+    }
+
+    fn brute_force(model: &RaceModel, time: usize, record: usize) -> Option<(usize, usize)> {
+        let wins: Vec<usize> = (0..=time)
+            .filter(|&hold| model.distance(time, hold) > record as u128)
+            .collect();
+        Some((*wins.first()?, *wins.last()?))
+    }
+
+    #[test]
+    fn standard_model() {
+        let model = RaceModel::default();
+        let outcome = model.race(7, 9);
+        let wins = outcome.wins.unwrap();
+        assert_eq!((wins.start(), wins.last()), (2, 5));
+        assert_eq!(wins.len(), Some(new_records(7, 9)));
+        assert_eq!((outcome.best_hold, outcome.best_distance), (3, 12));
+        assert_eq!(model.race(71530, 940200).wins.unwrap().len(), Some(71503));
+        assert_eq!(model.race(10, 25).wins, None);
+        assert_eq!(model.race(10, 25).best_distance, 25);
+    }
+
+    #[test]
+    fn model_matches_brute_force() {
+        let mut rng = TestRng::new(47);
+        for _ in 0..2000 {
+            let model = RaceModel {
+                acceleration: rng.below(4) + 1,
+                max_speed: [None, Some(rng.below(20))][rng.below(2)],
+                startup: rng.below(5),
+            };
+            let time = rng.below(60);
+            let record = rng.below(300);
+            let outcome = model.race(time, record);
+            let best = (0..=time)
+                .map(|hold| model.distance(time, hold))
+                .max()
+                .unwrap();
+            assert_eq!(outcome.best_distance, best, "{model:?} {time}");
+            assert_eq!(model.distance(time, outcome.best_hold), best);
+            assert_eq!(
+                outcome.wins.map(|wins| (wins.start(), wins.last())),
+                brute_force(&model, time, record),
+                "{model:?} {time} {record}"
+            );
+        }
+    }
+
+    #[test]
+    fn capped_model() {
+        let model = RaceModel {
+            acceleration: 2,
+            max_speed: Some(5),
+            startup: 1,
+        };
+        assert_eq!(model.speed(2), 4);
+        assert_eq!(model.speed(3), 5);
+        assert_eq!(model.distance(10, 3), 30);
+        let outcome = model.race(10, 20);
+        assert_eq!((outcome.best_hold, outcome.best_distance), (3, 30));
+        let wins = outcome.wins.unwrap();
+        assert_eq!((wins.start(), wins.last()), (2, 4));
+    }
+
+    #[test]
+    fn time_part_two() {
+        timeit(part_two);
+    }
+}
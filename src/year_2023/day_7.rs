@@ -0,0 +1,466 @@
+use std::{cmp::Ordering, collections::HashMap, error::Error, fmt::Display};
+
+use crate::registry::Sample;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category {
+    pub rank: usize,
+    pub name: String,
+    pub groups: Vec<usize>,
+}
+
+impl Category {
+    pub fn new(rank: usize, name: &str, groups: &[usize]) -> Self {
+        let mut groups = groups.to_vec();
+        groups.sort_by(|a, b| b.cmp(a));
+        Category {
+            rank,
+            name: name.to_string(),
+            groups,
+        }
+    }
+
+    fn reachable(&self, counts: &[usize], wild: usize) -> bool {
+        let missing: usize = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(idx, &size)| size.saturating_sub(counts.get(idx).copied().unwrap_or(0)))
+            .sum();
+        missing <= wild
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    DuplicateCard(char),
+    UnknownWild(char),
+    NoCategories,
+    DuplicateRank(usize),
+    UnknownCard(char),
+    WrongSize { expected: usize, found: usize },
+    MissingBid(String),
+    BadBid(String),
+    NoCategory(String),
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleError::DuplicateCard(c) => write!(f, "card {c:?} appears twice in the alphabet"),
+            RuleError::UnknownWild(c) => write!(f, "wild card {c:?} is not in the alphabet"),
+            RuleError::NoCategories => write!(f, "a rule set needs at least one category"),
+            RuleError::DuplicateRank(rank) => write!(f, "two categories share rank {rank}"),
+            RuleError::UnknownCard(c) => write!(f, "unknown card {c:?}"),
+            RuleError::WrongSize { expected, found } => {
+                write!(f, "expected {expected} cards but found {found}")
+            }
+            RuleError::MissingBid(line) => write!(f, "missing bid in {line:?}"),
+            RuleError::BadBid(bid) => write!(f, "invalid bid {bid:?}"),
+            RuleError::NoCategory(cards) => write!(f, "{cards} matches no category"),
+        }
+    }
+}
+
+impl Error for RuleError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    alphabet: Vec<char>,
+    wild: Option<usize>,
+    hand_size: usize,
+    categories: Vec<Category>,
+}
+
+impl RuleSet {
+    pub fn new(
+        alphabet: &str,
+        wild: Option<char>,
+        hand_size: usize,
+        mut categories: Vec<Category>,
+    ) -> Result<Self, RuleError> {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        for (idx, c) in alphabet.iter().enumerate() {
+            if alphabet[..idx].contains(c) {
+                return Err(RuleError::DuplicateCard(*c));
+            }
+        }
+        let wild = match wild {
+            Some(c) => Some(
+                alphabet
+                    .iter()
+                    .position(|&a| a == c)
+                    .ok_or(RuleError::UnknownWild(c))?,
+            ),
+            None => None,
+        };
+        if categories.is_empty() {
+            return Err(RuleError::NoCategories);
+        }
+        categories.sort_by_key(|category| category.rank);
+        for pair in categories.windows(2) {
+            if pair[0].rank == pair[1].rank {
+                return Err(RuleError::DuplicateRank(pair[0].rank));
+            }
+        }
+        Ok(RuleSet {
+            alphabet,
+            wild,
+            hand_size,
+            categories,
+        })
+    }
+
+    pub fn standard_categories() -> Vec<Category> {
+        vec![
+            Category::new(10, "High card", &[1]),
+            Category::new(20, "One pair", &[2]),
+            Category::new(30, "Two pair", &[2, 2]),
+            Category::new(40, "Three of a kind", &[3]),
+            Category::new(50, "Full house", &[3, 2]),
+            Category::new(60, "Four of a kind", &[4]),
+            Category::new(70, "Five of a kind", &[5]),
+        ]
+    }
+
+    pub fn standard() -> Self {
+        RuleSet::new("23456789TJQKA", None, 5, RuleSet::standard_categories()).unwrap()
+    }
+
+    pub fn jokers() -> Self {
+        RuleSet::new(
+            "J23456789TQKA",
+            Some('J'),
+            5,
+            RuleSet::standard_categories(),
+        )
+        .unwrap()
+    }
+
+    pub fn categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    pub fn category(&self, hand: &Hand) -> &Category {
+        &self.categories[hand.strength]
+    }
+
+    pub fn card(&self, c: char) -> Result<usize, RuleError> {
+        self.alphabet
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(RuleError::UnknownCard(c))
+    }
+
+    pub fn classify(&self, cards: &[usize]) -> Option<usize> {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        let mut wild = 0;
+        for &card in cards {
+            if Some(card) == self.wild {
+                wild += 1;
+            } else {
+                *counts.entry(card).or_insert(0) += 1;
+            }
+        }
+        let mut counts: Vec<usize> = counts.into_values().collect();
+        counts.sort_by(|a, b| b.cmp(a));
+        self.categories
+            .iter()
+            .rposition(|category| category.reachable(&counts, wild))
+    }
+
+    pub fn parse_hand(&self, line: &str) -> Result<Hand, RuleError> {
+        let mut parts = line.split_whitespace();
+        let cards = parts.next().unwrap_or_default();
+        let bid = parts
+            .next()
+            .ok_or_else(|| RuleError::MissingBid(line.to_string()))?;
+        let bid = bid
+            .parse()
+            .map_err(|_| RuleError::BadBid(bid.to_string()))?;
+        let hand = cards
+            .chars()
+            .map(|c| self.card(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        if hand.len() != self.hand_size {
+            return Err(RuleError::WrongSize {
+                expected: self.hand_size,
+                found: hand.len(),
+            });
+        }
+        let strength = self
+            .classify(&hand)
+            .ok_or_else(|| RuleError::NoCategory(cards.to_string()))?;
+        Ok(Hand {
+            bid,
+            hand,
+            strength,
+        })
+    }
+
+    pub fn parse_hands(&self, content: &str) -> Result<Vec<Hand>, RuleError> {
+        content.lines().map(|line| self.parse_hand(line)).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub bid: usize,
+    pub hand: Vec<usize>,
+    pub strength: usize,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength
+            .cmp(&other.strength)
+            .then_with(|| self.hand.cmp(&other.hand))
+            .then_with(|| self.bid.cmp(&other.bid))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand {
+    pub rank: usize,
+    pub hand: Hand,
+    pub winnings: usize,
+}
+
+pub fn rank_hands(mut hands: Vec<Hand>) -> Vec<RankedHand> {
+    hands.sort();
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(idx, hand)| RankedHand {
+            rank: idx + 1,
+            winnings: (idx + 1) * hand.bid,
+            hand,
+        })
+        .collect()
+}
+
+pub fn total_winnings(content: &str, rules: &RuleSet) -> usize {
+    let hands = rules.parse_hands(content).unwrap();
+    rank_hands(hands).iter().map(|ranked| ranked.winnings).sum()
+}
+
+pub fn part_one(content: &str) -> usize {
+    total_winnings(content, &RuleSet::standard())
+}
+
+pub fn part_two(content: &str) -> usize {
+    total_winnings(content, &RuleSet::jokers())
+}
+
+pub const SAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("6440"),
+    part_two: Some("5905"),
+}];
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{rank_hands, total_winnings, Category, RuleError, RuleSet, SAMPLE};
+
+    use crate::download_day;
+
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 7);
+        let res = super::part_one(&content);
+        println!("Part One: {res}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 7);
+        let res = super::part_two(&content);
+        println!("Part Two: {res}");
+    }
+
+    #[test]
+    fn part_one_sample() {
+        let hands = RuleSet::standard().parse_hands(SAMPLE).unwrap();
+        let res: usize = rank_hands(hands).iter().map(|ranked| ranked.winnings).sum();
+        assert_eq!(res, 6440);
+    }
+
+    #[test]
+    fn part_two_sample() {
+        let hands = RuleSet::jokers().parse_hands(SAMPLE).unwrap();
+        let res: usize = rank_hands(hands).iter().map(|ranked| ranked.winnings).sum();
+        assert_eq!(res, 5905);
+    }
+
+    #[test]
+    fn ranked_sample() {
+        let hands = RuleSet::standard().parse_hands(SAMPLE).unwrap();
+        let ranked: Vec<_> = rank_hands(hands)
+            .iter()
+            .map(|ranked| (ranked.rank, ranked.hand.bid, ranked.winnings))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                (1, 765, 765),
+                (2, 220, 440),
+                (3, 28, 84),
+                (4, 684, 2736),
+                (5, 483, 2415)
+            ]
+        );
+    }
+
+    #[test]
+    fn ordering_is_total_and_consistent() {
+        let rules = RuleSet::standard();
+        let a = rules.parse_hand("KK677 28").unwrap();
+        let b = rules.parse_hand("KK677 5").unwrap();
+        let c = rules.parse_hand("KTJJT 220").unwrap();
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert_eq!(a, a);
+        assert_ne!(a, b);
+        assert_eq!(b.cmp(&a), Ordering::Less);
+        assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        assert!(c < a);
+        assert_eq!(c.partial_cmp(&a), Some(c.cmp(&a)));
+
+        let hands = vec![a.clone(), b, c, a];
+        let ranked: Vec<_> = rank_hands(hands)
+            .iter()
+            .map(|ranked| ranked.hand.bid)
+            .collect();
+        assert_eq!(ranked, [220, 5, 28, 28]);
+    }
+
+    #[test]
+    fn categories() {
+        let rules = RuleSet::jokers();
+        let category = |line: &str| {
+            let hand = rules.parse_hand(line).unwrap();
+            rules.category(&hand).name.clone()
+        };
+        assert_eq!(category("T55J5 1"), "Four of a kind");
+        assert_eq!(category("JJJJJ 1"), "Five of a kind");
+        assert_eq!(category("2233J 1"), "Full house");
+        assert_eq!(category("2345J 1"), "One pair");
+        assert_eq!(category("23456 1"), "High card");
+        assert!(rules.parse_hand("JKKK2 1").unwrap() < rules.parse_hand("QQQQ2 1").unwrap());
+    }
+
+    #[test]
+    fn tens_wild() {
+        let rules = RuleSet::new(
+            "T23456789JQKA",
+            Some('T'),
+            5,
+            RuleSet::standard_categories(),
+        )
+        .unwrap();
+        assert_eq!(total_winnings(SAMPLE, &rules), 6843);
+        let hand = rules.parse_hand("TTKK2 1").unwrap();
+        assert_eq!(rules.category(&hand).name, "Four of a kind");
+    }
+
+    #[test]
+    fn six_card_hands() {
+        let mut categories = RuleSet::standard_categories();
+        categories.extend([
+            Category::new(35, "Three pair", &[2, 2, 2]),
+            Category::new(55, "Two triples", &[3, 3]),
+            Category::new(80, "Six of a kind", &[6]),
+        ]);
+        categories.reverse();
+        let rules = RuleSet::new("23456789TJQKA", None, 6, categories).unwrap();
+        let names: Vec<_> = ["223344 1", "222333 1", "AAAAAA 1", "22233K 1", "AKQJT9 1"]
+            .iter()
+            .map(|line| {
+                let hand = rules.parse_hand(line).unwrap();
+                rules.category(&hand).name.clone()
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Three pair",
+                "Two triples",
+                "Six of a kind",
+                "Full house",
+                "High card"
+            ]
+        );
+        let triples = rules.parse_hand("222333 1").unwrap();
+        let full_house = rules.parse_hand("AAAKK2 1").unwrap();
+        assert!(full_house < triples);
+        assert_eq!(rules.categories().last().unwrap().name, "Six of a kind");
+        assert_eq!(
+            rules.parse_hand("22334 1"),
+            Err(RuleError::WrongSize {
+                expected: 6,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_rules_and_hands() {
+        let categories = RuleSet::standard_categories;
+        assert_eq!(
+            RuleSet::new("23A2", None, 5, categories()),
+            Err(RuleError::DuplicateCard('2'))
+        );
+        assert_eq!(
+            RuleSet::new("23A", Some('J'), 5, categories()),
+            Err(RuleError::UnknownWild('J'))
+        );
+        assert_eq!(
+            RuleSet::new("23A", None, 5, vec![]),
+            Err(RuleError::NoCategories)
+        );
+        let duplicate = vec![
+            Category::new(1, "Pair", &[2]),
+            Category::new(1, "Trips", &[3]),
+        ];
+        assert_eq!(
+            RuleSet::new("23A", None, 5, duplicate),
+            Err(RuleError::DuplicateRank(1))
+        );
+        let rules = RuleSet::standard();
+        assert_eq!(
+            rules.parse_hand("2345X 1"),
+            Err(RuleError::UnknownCard('X'))
+        );
+        assert_eq!(
+            rules.parse_hand("23456"),
+            Err(RuleError::MissingBid("23456".to_string()))
+        );
+        assert_eq!(
+            rules.parse_hand("23456 x"),
+            Err(RuleError::BadBid("x".to_string()))
+        );
+        let pairs_only = RuleSet::new(
+            "23456789TJQKA",
+            None,
+            5,
+            vec![Category::new(1, "Pair", &[2])],
+        );
+        assert_eq!(
+            pairs_only.unwrap().parse_hand("23456 1"),
+            Err(RuleError::NoCategory("23456".to_string()))
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::{BTreeMap, HashMap};
+
+use num::integer::lcm;
+
+use crate::{regex, registry::Sample, repl::Explore};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Mapping<'a> {
+    pub start: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+pub fn parse_map(line: &str) -> Mapping<'_> {
+    let groups = regex!(r"(?P<start>.{3}) = \((?P<left>.{3}), (?P<right>.{3})\)")
+        .captures(line)
+        .unwrap()
+        .unwrap();
+    let start = groups.name("start").unwrap().as_str();
+    let left = groups.name("left").unwrap().as_str();
+    let right = groups.name("right").unwrap().as_str();
+    Mapping { start, left, right }
+}
+
+pub fn part_one(content: &str) -> usize {
+    let mut line_iter = content.lines();
+    let directions = line_iter.next().unwrap();
+    line_iter.next();
+    let mut maps = HashMap::new();
+    for line in line_iter {
+        let mapping = parse_map(line);
+        maps.insert(mapping.start, mapping);
+    }
+    let mut current = "AAA";
+    let mut count = 0;
+    let mut directions_iter = directions.chars().cycle();
+    while current != "ZZZ" {
+        match directions_iter.next().unwrap() {
+            'L' => current = maps[&current].left,
+            'R' => current = maps[&current].right,
+            _ => panic!("Unexpected Character"),
+        }
+        count += 1;
+    }
+    count
+}
+
+pub fn part_two(content: &str) -> usize {
+    let mut line_iter = content.lines();
+    let directions = line_iter.next().unwrap();
+    line_iter.next();
+    let mut maps = HashMap::new();
+    let mut starting: Vec<&str> = vec![];
+    for line in line_iter {
+        let mapping = parse_map(line);
+        maps.insert(mapping.start, mapping);
+        if mapping.start.ends_with('A') {
+            starting.push(mapping.start);
+        }
+    }
+    let mut counts = vec![];
+    let mut directions_iter = directions.chars().cycle();
+    for mut current in starting {
+        let mut count: usize = 0;
+        while !current.ends_with('Z') {
+            match directions_iter.next().unwrap() {
+                'L' => {
+                    current = maps[&current].left;
+                }
+                'R' => {
+                    current = maps[&current].right;
+                }
+                _ => panic!("Unexpected Character"),
+            }
+            count += 1;
+        }
+        counts.push(count);
+    }
+    counts.iter().fold(1, |acc, x| lcm(acc, *x))
+}
+
+pub struct Network<'a> {
+    directions: &'a str,
+    maps: BTreeMap<&'a str, Mapping<'a>>,
+}
+
+impl<'a> Network<'a> {
+    pub fn walk(&self, start: &str) -> Option<usize> {
+        let mut current = self.maps.get(start)?.start;
+        let mut count = 0;
+        let mut directions_iter = self.directions.chars().cycle();
+        while count == 0 || !current.ends_with('Z') {
+            let mapping = self.maps.get(current)?;
+            match directions_iter.next()? {
+                'L' => current = mapping.left,
+                'R' => current = mapping.right,
+                _ => panic!("Unexpected Character"),
+            }
+            count += 1;
+            if count > self.maps.len() * self.directions.len() {
+                return None;
+            }
+        }
+        Some(count)
+    }
+}
+
+impl<'a> Explore for Network<'a> {
+    fn show(&self) -> String {
+        let mut res = format!("{}\n", self.directions);
+        for mapping in self.maps.values() {
+            res.push_str(&format!("\n{mapping:?}"));
+        }
+        res
+    }
+
+    fn query(&self, command: &str, args: &[&str]) -> Option<String> {
+        match (command, args) {
+            ("node", [node]) => Some(match self.maps.get(node) {
+                Some(mapping) => format!("{mapping:?}"),
+                None => format!("No node {node}"),
+            }),
+            ("walk", [start]) => Some(match self.walk(start) {
+                Some(count) => format!("{start} reaches a Z node in {count} steps"),
+                None => format!("{start} never reaches a Z node"),
+            }),
+            _ => None,
+        }
+    }
+
+    fn commands(&self) -> &'static [&'static str] {
+        &["node <name>", "walk <start>"]
+    }
+}
+
+pub fn explore(content: &str) -> Box<dyn Explore + '_> {
+    let mut line_iter = content.lines();
+    let directions = line_iter.next().unwrap();
+    line_iter.next();
+    let maps = line_iter
+        .map(|line| {
+            let mapping = parse_map(line);
+            (mapping.start, mapping)
+        })
+        .collect();
+    Box::new(Network { directions, maps })
+}
+
+pub const SAMPLE: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+pub const SAMPLE_2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+pub const SAMPLES: &[Sample] = &[
+    Sample {
+        input: SAMPLE,
+        part_one: Some("2"),
+        part_two: None,
+    },
+    Sample {
+        input: SAMPLE_2,
+        part_one: None,
+        part_two: Some("6"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::download_day;
+
+    use super::{parse_map, SAMPLE, SAMPLE_2};
+    use num::integer::lcm;
+    #[test]
+    fn part_one() {
+        let content = download_day(2023, 8);
+        let count = super::part_one(&content);
+        println!("Part One: {count}");
+    }
+
+    #[test]
+    fn part_two() {
+        let content = download_day(2023, 8);
+        let res = super::part_two(&content);
+        println!("Part Two: {res}");
+    }
+
+    #[test]
+    fn part_one_sample() {
+        let content = SAMPLE;
+        let mut line_iter = content.lines();
+        let directions = line_iter.next().unwrap();
+        line_iter.next();
+        let mut maps = HashMap::new();
+        for line in line_iter {
+            let mapping = parse_map(line);
+            maps.insert(mapping.start, mapping);
+        }
+        let mut current = "AAA";
+        let mut count = 0;
+        let mut directions_iter = directions.chars().cycle();
+        while current != "ZZZ" {
+            match directions_iter.next().unwrap() {
+                'L' => current = maps[&current].left,
+                'R' => current = maps[&current].right,
+                _ => panic!("Unexpected Character"),
+            }
+            count += 1;
+        }
+        assert_eq!(count, 2)
+    }
+
+    #[test]
+    fn part_two_sample() {
+        let content = SAMPLE_2;
+        let mut line_iter = content.lines();
+        let directions = line_iter.next().unwrap();
+        line_iter.next();
+        let mut maps = HashMap::new();
+        let mut starting: Vec<&str> = vec![];
+        for line in line_iter {
+            let mapping = parse_map(line);
+            maps.insert(mapping.start, mapping);
+            if mapping.start.ends_with("A") {
+                starting.push(mapping.start);
+            }
+        }
+        let mut counts = vec![];
+        let mut directions_iter = directions.chars().cycle();
+        for mut current in starting {
+            let mut count: usize = 0;
+            while !current.ends_with("Z") {
+                match directions_iter.next().unwrap() {
+                    'L' => {
+                        current = maps[&current].left;
+                    }
+                    'R' => {
+                        current = maps[&current].right;
+                    }
+                    _ => panic!("Unexpected Character"),
+                }
+                count += 1;
+            }
+            counts.push(count);
+        }
+        let res = counts.iter().fold(1, |acc, x| lcm(acc, *x));
+        println!("Part Two: {res}");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::registry::Sample;
+
 pub fn extrapolate_last_number(numbers: &[isize]) -> isize {
     let first = numbers[0];
 
@@ -32,27 +34,50 @@ pub fn extrapolate_first_number(numbers: &[isize]) -> isize {
     return numbers[0] - extrapolate_first_number(&next);
 }
 
+pub fn part_one(content: &str) -> isize {
+    content
+        .lines()
+        .map(|s| {
+            s.split_whitespace()
+                .map(|s| s.parse::<isize>().unwrap())
+                .collect::<Vec<isize>>()
+        })
+        .map(|nums| extrapolate_last_number(&nums))
+        .sum()
+}
+
+pub fn part_two(content: &str) -> isize {
+    content
+        .lines()
+        .map(|s| {
+            s.split_whitespace()
+                .map(|s| s.parse::<isize>().unwrap())
+                .collect::<Vec<isize>>()
+        })
+        .map(|nums| extrapolate_first_number(&nums))
+        .sum()
+}
+
+pub const SAMPLE: &str = "10  13  16  21  30  45";
+
+pub const SAMPLES: &[Sample] = &[Sample {
+    input: SAMPLE,
+    part_one: Some("68"),
+    part_two: Some("5"),
+}];
+
 #[cfg(test)]
 mod tests {
+    use super::SAMPLE;
     use crate::{
-        day_9::{extrapolate_first_number, extrapolate_last_number},
         download_day,
+        year_2023::day_9::{extrapolate_first_number, extrapolate_last_number},
     };
 
-    const SAMPLE: &str = "10  13  16  21  30  45";
-
     #[test]
     fn part_two() {
         let content = download_day(2023, 9);
-        let res: isize = content
-            .lines()
-            .map(|s| {
-                s.split_whitespace()
-                    .map(|s| s.parse::<isize>().unwrap())
-                    .collect::<Vec<isize>>()
-            })
-            .map(|nums| extrapolate_first_number(&nums))
-            .sum();
+        let res = super::part_two(&content);
 
         println!("Part One: {res}");
     }
@@ -60,15 +85,7 @@ mod tests {
     #[test]
     fn part_one() {
         let content = download_day(2023, 9);
-        let res: isize = content
-            .lines()
-            .map(|s| {
-                s.split_whitespace()
-                    .map(|s| s.parse::<isize>().unwrap())
-                    .collect::<Vec<isize>>()
-            })
-            .map(|nums| extrapolate_last_number(&nums))
-            .sum();
+        let res = super::part_one(&content);
 
         println!("Part One: {res}");
     }